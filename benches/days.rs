@@ -0,0 +1,41 @@
+//! Benchmark parsing and both parts of every registered day against personal puzzle inputs.
+//!
+//! Inputs are read from the input store (`~/.cache/aoc/2024`, or `AOC_INPUTS`). Days without an
+//! input are skipped.
+
+use advent_of_code_2024::inputs::InputStore;
+use advent_of_code_2024::{Part, DAYS};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+fn bench_days(c: &mut Criterion) {
+    let inputs = InputStore::from_env().expect("cannot find the input store");
+
+    for day in DAYS {
+        let path = inputs.path(day.number);
+        let Ok(input) = std::fs::read_to_string(&path) else {
+            eprintln!("skipping day {}: cannot read {path:?}", day.number);
+            continue;
+        };
+        let parsed = day.parse(&input).expect("cannot parse input");
+
+        let mut group = c.benchmark_group(format!("day{:02}", day.number));
+
+        group.bench_function("parse", |b| b.iter(|| day.parse(black_box(&input))));
+
+        for (name, part) in [("part_one", Part::One), ("part_two", Part::Two)] {
+            if day.solve_parsed(&parsed, part).is_err() {
+                eprintln!("skipping day {} part {part}: it fails", day.number);
+                continue;
+            }
+            group.bench_function(name, |b| {
+                b.iter(|| day.solve_parsed(black_box(&parsed), part))
+            });
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -0,0 +1,349 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use eyre::{Result, WrapErr};
+use serde::{Deserialize, Serialize};
+
+use crate::inputs::InputStore;
+use crate::Part;
+
+/// The answers submitted for every day and part, e.g.
+///
+/// ```toml
+/// [day01.a]
+/// accepted = 1234
+/// wrong = [{ answer = 1300, hint = "too-high" }, { answer = 1200 }]
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Ledger {
+    days: BTreeMap<String, BTreeMap<String, Entry>>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Entry {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accepted: Option<AnswerValue>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<Guess>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Guess {
+    pub answer: AnswerValue,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Hint>,
+}
+
+/// What the puzzle page says about a wrong answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+/// An answer as written in the ledger, a TOML integer where possible so that hints can be
+/// compared numerically.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AnswerValue {
+    Integer(i64),
+    Text(String),
+}
+
+impl From<&str> for AnswerValue {
+    fn from(s: &str) -> Self {
+        match s.parse() {
+            Ok(n) => AnswerValue::Integer(n),
+            Err(_) => AnswerValue::Text(s.to_string()),
+        }
+    }
+}
+
+impl Display for AnswerValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AnswerValue::Integer(n) => write!(f, "{n}"),
+            AnswerValue::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// How a result compares with what was submitted before.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// Nothing is known, the result can be submitted.
+    Unverified,
+    Correct,
+    Mismatch {
+        accepted: AnswerValue,
+    },
+    KnownWrong {
+        hint: Option<Hint>,
+    },
+    /// The result is beyond a guess that was already too high or too low.
+    OutOfRange {
+        guess: AnswerValue,
+        hint: Hint,
+    },
+}
+
+impl Verdict {
+    pub fn is_wrong(&self) -> bool {
+        !matches!(self, Verdict::Unverified | Verdict::Correct)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Verdict::Unverified => Ok(()),
+            Verdict::Correct => write!(f, "ok"),
+            Verdict::Mismatch { accepted } => write!(f, "WRONG, accepted {accepted}"),
+            Verdict::KnownWrong { hint: None } => write!(f, "WRONG, rejected before"),
+            Verdict::KnownWrong { hint: Some(hint) } => {
+                write!(f, "WRONG, rejected before as {hint}")
+            }
+            Verdict::OutOfRange { guess, hint } => write!(f, "WRONG, {guess} was already {hint}"),
+        }
+    }
+}
+
+impl Entry {
+    pub fn check(&self, result: &str) -> Verdict {
+        let result = AnswerValue::from(result);
+
+        if let Some(accepted) = &self.accepted {
+            return if *accepted == result {
+                Verdict::Correct
+            } else {
+                Verdict::Mismatch {
+                    accepted: accepted.clone(),
+                }
+            };
+        }
+
+        if let Some(guess) = self.wrong.iter().find(|guess| guess.answer == result) {
+            return Verdict::KnownWrong { hint: guess.hint };
+        }
+
+        let AnswerValue::Integer(n) = result else {
+            return Verdict::Unverified;
+        };
+
+        self.wrong
+            .iter()
+            .find_map(|guess| match (&guess.answer, guess.hint?) {
+                (AnswerValue::Integer(g), Hint::TooHigh) if n >= *g => Some((guess, Hint::TooHigh)),
+                (AnswerValue::Integer(g), Hint::TooLow) if n <= *g => Some((guess, Hint::TooLow)),
+                _ => None,
+            })
+            .map_or(Verdict::Unverified, |(guess, hint)| Verdict::OutOfRange {
+                guess: guess.answer.clone(),
+                hint,
+            })
+    }
+}
+
+fn day_key(day: u32) -> String {
+    format!("day{day:02}")
+}
+
+impl Ledger {
+    /// `AOC_ANSWERS` if set, otherwise `answers.toml` next to the stored inputs. `AOC_ANSWERS`
+    /// used to name a directory of answer files, which [`Ledger::load`] still reads.
+    pub fn default_path() -> Result<PathBuf> {
+        match std::env::var_os("AOC_ANSWERS") {
+            Some(path) => Ok(PathBuf::from(path)),
+            None => Ok(InputStore::from_env()?.dir().join("answers.toml")),
+        }
+    }
+
+    /// Read the ledger, which is empty if the file doesn't exist yet. A directory is read as
+    /// answer files from before the ledger, see [`Ledger::import_answer_files`].
+    pub fn load(path: &Path) -> Result<Self> {
+        if path.is_dir() {
+            let mut ledger = Self::default();
+            ledger.import_answer_files(path)?;
+            return Ok(ledger);
+        }
+
+        match std::fs::read_to_string(path) {
+            Ok(s) => toml::from_str(&s).wrap_err_with(|| format!("cannot parse {path:?}")),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).wrap_err_with(|| format!("cannot read {path:?}")),
+        }
+    }
+
+    /// Accept the answers in a directory of answer files from before the ledger, which hold the
+    /// accepted answers of a day in `dayNN.toml`, e.g. `a = 1234` and `b = "some text"`. Returns
+    /// how many answers were accepted.
+    pub fn import_answer_files(&mut self, dir: &Path) -> Result<usize> {
+        let mut imported = 0;
+
+        for entry in std::fs::read_dir(dir).wrap_err_with(|| format!("cannot read {dir:?}"))? {
+            let path = entry?.path();
+            let Some(day) = path
+                .file_name()
+                .and_then(|name| name.to_str()?.strip_prefix("day")?.strip_suffix(".toml"))
+                .and_then(|day| day.parse().ok())
+            else {
+                continue;
+            };
+
+            let answers: toml::Table = std::fs::read_to_string(&path)
+                .wrap_err_with(|| format!("cannot read {path:?}"))?
+                .parse()
+                .wrap_err_with(|| format!("cannot parse {path:?}"))?;
+
+            for part in Part::ALL {
+                let answer = match answers.get(&part.to_string()) {
+                    Some(toml::Value::String(s)) => s.clone(),
+                    Some(value) => value.to_string(),
+                    None => continue,
+                };
+                if let Some(accepted) = self
+                    .entry(day, part)
+                    .and_then(|entry| entry.accepted.as_ref())
+                {
+                    eyre::ensure!(
+                        accepted.to_string() == answer,
+                        "{path:?} has {answer} for day {day} part {part}, the ledger {accepted}"
+                    );
+                }
+                self.accept(day, part, &answer)
+                    .wrap_err_with(|| format!("in {path:?}"))?;
+                imported += 1;
+            }
+        }
+
+        Ok(imported)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        eyre::ensure!(
+            !path.is_dir(),
+            "{path:?} is a directory of answer files from before the ledger, which can only be \
+             read. Copy them into a ledger file with `aoc answer import {}`, then point \
+             AOC_ANSWERS or --answers at that file",
+            path.display()
+        );
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).wrap_err_with(|| format!("cannot create {dir:?}"))?;
+        }
+        std::fs::write(path, toml::to_string_pretty(self)?)
+            .wrap_err_with(|| format!("cannot write {path:?}"))
+    }
+
+    pub fn entry(&self, day: u32, part: Part) -> Option<&Entry> {
+        self.days.get(&day_key(day))?.get(&part.to_string())
+    }
+
+    fn entry_mut(&mut self, day: u32, part: Part) -> &mut Entry {
+        self.days
+            .entry(day_key(day))
+            .or_default()
+            .entry(part.to_string())
+            .or_default()
+    }
+
+    pub fn check(&self, day: u32, part: Part, result: &str) -> Verdict {
+        self.entry(day, part)
+            .map_or(Verdict::Unverified, |entry| entry.check(result))
+    }
+
+    pub fn accept(&mut self, day: u32, part: Part, answer: &str) -> Result<()> {
+        let answer = AnswerValue::from(answer);
+        let entry = self.entry_mut(day, part);
+        eyre::ensure!(
+            entry.wrong.iter().all(|guess| guess.answer != answer),
+            "{answer} was rejected before"
+        );
+        entry.accepted = Some(answer);
+        Ok(())
+    }
+
+    pub fn reject(&mut self, day: u32, part: Part, answer: &str, hint: Option<Hint>) -> Result<()> {
+        let answer = AnswerValue::from(answer);
+        let entry = self.entry_mut(day, part);
+        eyre::ensure!(
+            entry.accepted.as_ref() != Some(&answer),
+            "{answer} is the accepted answer"
+        );
+        match entry.wrong.iter_mut().find(|guess| guess.answer == answer) {
+            Some(guess) => guess.hint = hint.or(guess.hint),
+            None => entry.wrong.push(Guess { answer, hint }),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let ledger: Ledger = toml::from_str(
+            r#"
+            [day01.a]
+            accepted = 11
+
+            [day01.b]
+            wrong = [{ answer = 40, hint = "too-high" }, { answer = 20, hint = "too-low" }, { answer = 25 }]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(ledger.check(1, Part::One, "11"), Verdict::Correct);
+        assert_eq!(
+            ledger.check(1, Part::One, "12"),
+            Verdict::Mismatch {
+                accepted: AnswerValue::Integer(11)
+            }
+        );
+        assert_eq!(
+            ledger.check(1, Part::Two, "25"),
+            Verdict::KnownWrong { hint: None }
+        );
+        assert_eq!(
+            ledger.check(1, Part::Two, "41"),
+            Verdict::OutOfRange {
+                guess: AnswerValue::Integer(40),
+                hint: Hint::TooHigh
+            }
+        );
+        assert_eq!(ledger.check(1, Part::Two, "31"), Verdict::Unverified);
+        assert_eq!(ledger.check(2, Part::One, "1"), Verdict::Unverified);
+    }
+
+    #[test]
+    fn test_import_answer_files() {
+        let dir = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("day01.toml"), "a = 11\nb = \"some text\"\n").unwrap();
+        std::fs::write(dir.join("notes.txt"), "not an answer file").unwrap();
+
+        let ledger = Ledger::load(&dir).unwrap();
+        assert_eq!(ledger.check(1, Part::One, "11"), Verdict::Correct);
+        assert_eq!(ledger.check(1, Part::Two, "some text"), Verdict::Correct);
+        assert!(ledger.save(&dir).is_err());
+
+        let mut ledger = Ledger::default();
+        ledger.accept(1, Part::One, "12").unwrap();
+        assert!(ledger.import_answer_files(&dir).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,688 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use advent_of_code_2024::answers::{Hint, Ledger};
+use advent_of_code_2024::days::day06::{self, Day06, Mark};
+use advent_of_code_2024::days::day08::{self, Day08, Location};
+use advent_of_code_2024::days::day09::{self, Day09};
+use advent_of_code_2024::days::day10::{self, Connectivity, Day10, TrailRules};
+use advent_of_code_2024::grid::Grid;
+use advent_of_code_2024::image::{self, GifWriter, Rgb};
+use advent_of_code_2024::inputs::{self, InputStore};
+use advent_of_code_2024::params::Params;
+use advent_of_code_2024::{Day, Part, Solution, DAYS};
+use clap::{Args, Parser, Subcommand};
+use eyre::{Result, WrapErr};
+use tracing::warn;
+use tracing_subscriber::EnvFilter;
+
+const CLEAR_SCREEN: &str = "\x1b[2J";
+const CURSOR_HOME: &str = "\x1b[H";
+const CLEAR_LINE: &str = "\x1b[K";
+
+#[derive(Debug, Parser)]
+#[command(
+    about = "Advent of Code 2024 solutions",
+    after_help = "Environment variables:
+  AOC_INPUTS   The directory of stored inputs, instead of the cache directory.
+  AOC_ANSWERS  The answer ledger file, instead of `answers.toml` in the inputs directory. A
+               directory of `dayNN.toml` answer files from before the ledger is read too, import
+               it with `aoc answer import` to record answers.
+  RUST_LOG     Filter the debug output, like `-v`."
+)]
+struct Cli {
+    /// Print debug output of the solutions to stderr, `-vv` for trace output. `RUST_LOG` takes
+    /// precedence when set.
+    #[arg(long, short, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve one day, or all of them, and print the answers with timings.
+    Run(RunArgs),
+    /// Manage the stored puzzle inputs.
+    #[command(subcommand)]
+    Input(InputCommand),
+    /// Record what the puzzle page said about a submitted answer.
+    #[command(subcommand)]
+    Answer(AnswerCommand),
+    /// Score and rate the trail heads of a day 10 map under different trail rules.
+    Trails(TrailsArgs),
+    /// Draw the state of a puzzle as a PNG, or as an animated GIF if the output ends in `.gif`.
+    /// Days 6, 8, 9 and 10 can be drawn.
+    Picture(PictureArgs),
+}
+
+#[derive(Debug, Args)]
+struct PictureArgs {
+    /// The day to draw.
+    day: u32,
+
+    /// The part whose state to draw, `a` or `b`.
+    #[arg(default_value = "a")]
+    part: Part,
+
+    /// Where to write the picture. Animations drawn as PNGs get the frame number added to the file
+    /// name.
+    #[arg(long, short)]
+    output: PathBuf,
+
+    /// The width and height of a cell in pixels.
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..))]
+    scale: u32,
+
+    /// Frames per second of an animated GIF.
+    #[arg(long, default_value_t = 30, value_parser = clap::value_parser!(u32).range(1..))]
+    speed: u32,
+
+    /// Only draw every this many frames of an animation. The last frame is always drawn.
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    every: u32,
+
+    /// Read the input from this file instead of the input store, `-` reads stdin.
+    #[arg(long, short)]
+    input: Option<PathBuf>,
+
+    #[command(flatten)]
+    store: StoreArgs,
+}
+
+#[derive(Debug, Args)]
+struct TrailsArgs {
+    /// The height of trail heads.
+    #[arg(long, default_value_t = 0)]
+    start: u32,
+
+    /// The height of peaks.
+    #[arg(long, default_value_t = 9)]
+    end: u32,
+
+    /// The least a trail goes up with every step.
+    #[arg(long, default_value_t = 1)]
+    min_step: u32,
+
+    /// The most a trail goes up with every step.
+    #[arg(long, default_value_t = 1)]
+    max_step: u32,
+
+    /// Let trails move diagonally too.
+    #[arg(long)]
+    diagonal: bool,
+
+    /// Read the map from this file instead of the input store, `-` reads stdin.
+    #[arg(long, short)]
+    input: Option<PathBuf>,
+
+    #[command(flatten)]
+    store: StoreArgs,
+}
+
+#[derive(Debug, Subcommand)]
+enum AnswerCommand {
+    /// Record the accepted answer.
+    Accept {
+        day: u32,
+        part: Part,
+        answer: String,
+        #[command(flatten)]
+        ledger: LedgerArgs,
+    },
+    /// Record a rejected answer.
+    Reject {
+        day: u32,
+        part: Part,
+        answer: String,
+        /// The answer was too high.
+        #[arg(long, conflicts_with = "too_low")]
+        too_high: bool,
+        /// The answer was too low.
+        #[arg(long)]
+        too_low: bool,
+        #[command(flatten)]
+        ledger: LedgerArgs,
+    },
+    /// Copy the accepted answers from a directory of `dayNN.toml` files, where answers were
+    /// recorded before the ledger, into the ledger.
+    Import {
+        dir: PathBuf,
+        #[command(flatten)]
+        ledger: LedgerArgs,
+    },
+}
+
+#[derive(Debug, Args)]
+struct LedgerArgs {
+    /// The answer ledger. Defaults to `AOC_ANSWERS`, or `answers.toml` in the inputs directory. A
+    /// directory of answer files from before the ledger can be read but not written.
+    #[arg(long)]
+    answers: Option<PathBuf>,
+}
+
+impl LedgerArgs {
+    fn path(&self) -> Result<PathBuf> {
+        match &self.answers {
+            Some(path) => Ok(path.clone()),
+            None => Ledger::default_path(),
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum InputCommand {
+    /// Copy a downloaded input into the store, fixing line endings on the way.
+    Import {
+        /// The day the input belongs to.
+        day: u32,
+        /// The file to import.
+        path: PathBuf,
+        /// Replace an existing, different input.
+        #[arg(long)]
+        force: bool,
+        #[command(flatten)]
+        store: StoreArgs,
+    },
+    /// Show which days have a stored input.
+    List {
+        #[command(flatten)]
+        store: StoreArgs,
+    },
+}
+
+#[derive(Debug, Args)]
+struct StoreArgs {
+    /// The directory containing the `dayNN.txt` inputs. Defaults to `AOC_INPUTS`, or
+    /// `~/.cache/aoc/2024`.
+    #[arg(long)]
+    inputs: Option<PathBuf>,
+}
+
+impl StoreArgs {
+    fn store(&self) -> Result<InputStore> {
+        match &self.inputs {
+            Some(dir) => Ok(InputStore::new(dir)),
+            None => InputStore::from_env(),
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+    /// The day to solve.
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u32>,
+
+    /// The part to solve, `a` or `b`. Both parts are solved if omitted.
+    part: Option<Part>,
+
+    /// Solve every registered day.
+    #[arg(long)]
+    all: bool,
+
+    /// Read the input from this file instead of the input store, `-` reads stdin.
+    #[arg(long, short, conflicts_with = "all")]
+    input: Option<PathBuf>,
+
+    /// Set a puzzle parameter that the puzzle text gives rather than the input, e.g.
+    /// `--param width=11` for the day 14 example. Can be repeated.
+    #[arg(long = "param", value_name = "NAME=VALUE", conflicts_with = "all")]
+    params: Vec<String>,
+
+    /// Animate the solution in the terminal instead of printing the answers. Only day 6 can be
+    /// animated.
+    #[arg(long, conflicts_with = "all")]
+    visualise: bool,
+
+    /// Print every step of the solution instead of the answers, or every N steps. Only day 9 can
+    /// be traced.
+    #[arg(
+        long,
+        value_name = "N",
+        num_args = 0..=1,
+        default_missing_value = "1",
+        value_parser = clap::value_parser!(u32).range(1..),
+        conflicts_with_all = ["all", "visualise"],
+    )]
+    trace: Option<u32>,
+
+    /// Frames per second for `--visualise`.
+    #[arg(long, default_value_t = 30, value_parser = clap::value_parser!(u32).range(1..))]
+    speed: u32,
+
+    #[command(flatten)]
+    store: StoreArgs,
+
+    #[command(flatten)]
+    ledger: LedgerArgs,
+}
+
+#[derive(Debug)]
+struct Row {
+    day: u32,
+    part: String,
+    answer: String,
+    elapsed: Option<Duration>,
+    check: String,
+}
+
+fn read_input(path: Option<&Path>, store: &InputStore, day: u32) -> Result<String> {
+    let input = match path {
+        Some(path) if path == Path::new("-") => advent_of_code_2024::read_input()?,
+        Some(path) => {
+            std::fs::read_to_string(path).wrap_err_with(|| format!("cannot read {path:?}"))?
+        }
+        None => return store.load(day),
+    };
+
+    for issue in inputs::check(&input) {
+        warn!("day {day}: {issue}");
+    }
+
+    Ok(input)
+}
+
+// Return the number of failed or wrong parts, or the error if the input cannot be read or parsed.
+fn run_day(
+    args: &RunArgs,
+    store: &InputStore,
+    ledger: &Ledger,
+    day: &Day,
+    parts: &[Part],
+    rows: &mut Vec<Row>,
+) -> Result<usize> {
+    let input = read_input(args.input.as_deref(), store, day.number)?;
+    let params: Params = args.params.join("\n").parse()?;
+    let report = day.run(&input, &params, parts)?;
+
+    rows.push(Row {
+        day: day.number,
+        part: "parse".to_string(),
+        answer: String::new(),
+        elapsed: Some(report.parse_elapsed),
+        check: String::new(),
+    });
+
+    let mut failures = 0;
+
+    for answer in report.answers {
+        let (value, check) = match answer.value {
+            Ok(value) => {
+                let verdict = ledger.check(day.number, answer.part, &value);
+                if verdict.is_wrong() {
+                    failures += 1;
+                }
+                (value, verdict.to_string())
+            }
+            Err(e) => {
+                failures += 1;
+                (format!("error: {e:#}"), String::new())
+            }
+        };
+        rows.push(Row {
+            day: day.number,
+            part: answer.part.to_string(),
+            answer: value,
+            elapsed: Some(answer.elapsed),
+            check,
+        });
+    }
+
+    Ok(failures)
+}
+
+fn print_table(rows: &[Row]) {
+    let answer_width = rows
+        .iter()
+        .map(|row| row.answer.len())
+        .chain(std::iter::once("answer".len()))
+        .max()
+        .unwrap_or(0);
+
+    println!(
+        "{:>3}  {:<5}  {:<answer_width$}  {:>10}  check",
+        "day", "part", "answer", "time"
+    );
+
+    for row in rows {
+        let elapsed = row
+            .elapsed
+            .map(|elapsed| format!("{elapsed:.2?}"))
+            .unwrap_or_default();
+        let line = format!(
+            "{:>3}  {:<5}  {:<answer_width$}  {:>10}  {}",
+            row.day, row.part, row.answer, elapsed, row.check
+        );
+        println!("{}", line.trim_end());
+    }
+
+    let total: Duration = rows.iter().filter_map(|row| row.elapsed).sum();
+    println!(
+        "{:>3}  {:<5}  {:<answer_width$}  {:>10}",
+        "",
+        "total",
+        "",
+        format!("{total:.2?}")
+    );
+}
+
+fn visualise(args: &RunArgs, number: u32, parts: &[Part]) -> Result<()> {
+    eyre::ensure!(number == 6, "day {number} cannot be visualised");
+    let input = read_input(args.input.as_deref(), &args.store.store()?, number)?;
+    let input = Day06::parse(&input)?;
+    let delay = Duration::from_secs_f64(1.0 / f64::from(args.speed));
+
+    let mut stdout = std::io::stdout().lock();
+    write!(stdout, "{CLEAR_SCREEN}")?;
+    for &part in parts {
+        day06::visualise(&input, part, |frame| {
+            write!(
+                stdout,
+                "{CURSOR_HOME}{}{CLEAR_LINE}\n{}",
+                frame.caption, frame.marks
+            )?;
+            stdout.flush()?;
+            std::thread::sleep(delay);
+            Ok(())
+        })?;
+    }
+    Ok(())
+}
+
+fn trace(args: &RunArgs, number: u32, parts: &[Part], every: u32) -> Result<()> {
+    eyre::ensure!(number == 9, "day {number} cannot be traced");
+    let input = read_input(args.input.as_deref(), &args.store.store()?, number)?;
+    let disk_map = Day09::parse(&input)?;
+
+    let mut stdout = std::io::stdout().lock();
+    for &part in parts {
+        writeln!(stdout, "part {part}")?;
+        day09::trace(&disk_map, part, every as usize, &mut stdout)?;
+    }
+    Ok(())
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    let days: Vec<&Day> = match args.day {
+        Some(number) => vec![advent_of_code_2024::day(number)
+            .ok_or_else(|| eyre::eyre!("day {number} is not implemented"))?],
+        None => DAYS.iter().collect(),
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    if let (true, Some(number)) = (args.visualise, args.day) {
+        return visualise(&args, number, &parts);
+    }
+    if let (Some(every), Some(number)) = (args.trace, args.day) {
+        return trace(&args, number, &parts, every);
+    }
+
+    let store = args.store.store()?;
+    let ledger = Ledger::load(&args.ledger.path()?)?;
+    let mut rows = Vec::new();
+    let mut failures = 0;
+
+    for day in days {
+        match run_day(&args, &store, &ledger, day, &parts, &mut rows) {
+            Ok(failed_parts) => failures += failed_parts,
+            Err(e) if args.all => {
+                eprintln!("Error: {e:?}");
+                rows.push(Row {
+                    day: day.number,
+                    part: "-".to_string(),
+                    answer: format!("error: {e}"),
+                    elapsed: None,
+                    check: String::new(),
+                });
+                failures += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    print_table(&rows);
+
+    eyre::ensure!(failures == 0, "{failures} part(s) failed");
+    Ok(())
+}
+
+fn input(command: InputCommand) -> Result<()> {
+    match command {
+        InputCommand::Import {
+            day,
+            path,
+            force,
+            store,
+        } => {
+            eyre::ensure!((1..=25).contains(&day), "there is no day {day}");
+            let store = store.store()?;
+            for issue in store.import(day, &path, force)? {
+                println!("fixed: {issue}");
+            }
+            println!("imported {path:?} as {:?}", store.path(day));
+        }
+        InputCommand::List { store } => {
+            let store = store.store()?;
+            println!("{}", store.dir().display());
+            for number in 1..=25 {
+                let stored = if store.contains(number) {
+                    "stored"
+                } else {
+                    "-"
+                };
+                let solved = if advent_of_code_2024::day(number).is_some() {
+                    ""
+                } else {
+                    "  (not implemented)"
+                };
+                println!("day {number:>2}  {stored}{solved}");
+            }
+        }
+    }
+    Ok(())
+}
+
+fn answer(command: AnswerCommand) -> Result<()> {
+    let path = match &command {
+        AnswerCommand::Accept { ledger, .. }
+        | AnswerCommand::Reject { ledger, .. }
+        | AnswerCommand::Import { ledger, .. } => ledger.path()?,
+    };
+    let mut ledger = Ledger::load(&path)?;
+
+    match command {
+        AnswerCommand::Accept {
+            day, part, answer, ..
+        } => ledger.accept(day, part, &answer)?,
+        AnswerCommand::Reject {
+            day,
+            part,
+            answer,
+            too_high,
+            too_low,
+            ..
+        } => {
+            let hint = match (too_high, too_low) {
+                (true, _) => Some(Hint::TooHigh),
+                (_, true) => Some(Hint::TooLow),
+                _ => None,
+            };
+            ledger.reject(day, part, &answer, hint)?
+        }
+        AnswerCommand::Import { dir, .. } => {
+            let imported = ledger.import_answer_files(&dir)?;
+            println!("imported {imported} answers from {dir:?}");
+        }
+    }
+
+    ledger.save(&path)?;
+    println!("updated {path:?}");
+    Ok(())
+}
+
+fn trails(args: TrailsArgs) -> Result<()> {
+    let input = read_input(args.input.as_deref(), &args.store.store()?, 10)?;
+    let map = Day10::parse(&input)?;
+    let rules = TrailRules {
+        start: args.start,
+        end: args.end,
+        steps: args.min_step..=args.max_step,
+        connectivity: if args.diagonal {
+            Connectivity::Eight
+        } else {
+            Connectivity::Four
+        },
+    };
+
+    let summary = day10::summarise(&map, &rules)?;
+    println!("trail heads  {}", summary.trail_heads);
+    println!("score        {}", summary.score);
+    println!("rating       {}", summary.rating);
+    Ok(())
+}
+
+// Writes the frames of a picture as a PNG, numbered PNGs or a GIF.
+struct Pictures<'a> {
+    args: &'a PictureArgs,
+    gif: Option<GifWriter<BufWriter<File>>>,
+    frames: usize,
+    skipped: Option<Grid<Rgb>>,
+}
+
+impl<'a> Pictures<'a> {
+    fn new(args: &'a PictureArgs) -> Self {
+        Self {
+            args,
+            gif: None,
+            frames: 0,
+            skipped: None,
+        }
+    }
+
+    fn create(path: &Path) -> Result<BufWriter<File>> {
+        let file = File::create(path).wrap_err_with(|| format!("cannot create {path:?}"))?;
+        Ok(BufWriter::new(file))
+    }
+
+    fn write(&mut self, grid: &Grid<Rgb>, frame: Option<usize>) -> Result<()> {
+        let args = self.args;
+        let scale = args.scale as usize;
+
+        if args.output.extension().is_some_and(|e| e == "gif") {
+            let gif = match &mut self.gif {
+                Some(gif) => gif,
+                None => self.gif.insert(GifWriter::new(
+                    Self::create(&args.output)?,
+                    grid.dimension(),
+                    scale,
+                    Duration::from_secs_f64(1.0 / f64::from(args.speed)),
+                )?),
+            };
+            return gif.write_frame(grid, |&rgb| rgb);
+        }
+
+        let path = match frame {
+            Some(frame) => {
+                let stem = args
+                    .output
+                    .file_stem()
+                    .unwrap_or_default()
+                    .to_string_lossy();
+                let extension = args.output.extension().unwrap_or("png".as_ref());
+                args.output
+                    .with_file_name(format!("{stem}-{frame:05}"))
+                    .with_extension(extension)
+            }
+            None => args.output.clone(),
+        };
+        image::write_png(Self::create(&path)?, grid, scale, |&rgb| rgb)
+    }
+
+    // Add a frame of an animation.
+    fn frame(&mut self, grid: Grid<Rgb>) -> Result<()> {
+        let frame = self.frames;
+        self.frames += 1;
+
+        if frame.is_multiple_of(self.args.every as usize) {
+            self.skipped = None;
+            self.write(&grid, Some(frame))
+        } else {
+            self.skipped = Some(grid);
+            Ok(())
+        }
+    }
+
+    fn finish(mut self) -> Result<()> {
+        if let Some(grid) = self.skipped.take() {
+            self.write(&grid, Some(self.frames - 1))?;
+        }
+        Ok(())
+    }
+}
+
+fn picture(args: PictureArgs) -> Result<()> {
+    let input = read_input(args.input.as_deref(), &args.store.store()?, args.day)?;
+    let mut pictures = Pictures::new(&args);
+
+    match args.day {
+        6 => {
+            let input = Day06::parse(&input)?;
+            day06::visualise(&input, args.part, |frame| {
+                pictures.frame(frame.marks.map(Mark::colour))
+            })?;
+        }
+        8 => {
+            let map = day08::antinode_map(&Day08::parse(&input)?, args.part);
+            pictures.write(&map.map(Location::colour), None)?;
+        }
+        9 => {
+            let disk = day09::disk_layout(&Day09::parse(&input)?, args.part)?;
+            pictures.write(&disk.map(day09::colour), None)?;
+        }
+        10 => {
+            let rules = TrailRules::default();
+            let network = day10::trail_network(&Day10::parse(&input)?, &rules)?;
+            pictures.write(&network.map(|spot| spot.colour(&rules)), None)?;
+        }
+        day => eyre::bail!("day {day} cannot be drawn"),
+    }
+
+    pictures.finish()
+}
+
+fn install_tracing(verbose: u8) {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| {
+        EnvFilter::new(match verbose {
+            0 => "warn",
+            1 => "debug",
+            _ => "trace",
+        })
+    });
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .without_time()
+        .init();
+}
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse();
+    install_tracing(cli.verbose);
+
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Input(command) => input(command),
+        Command::Answer(command) => answer(command),
+        Command::Trails(args) => trails(args),
+        Command::Picture(args) => picture(args),
+    }
+}
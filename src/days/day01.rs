@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use eyre::Result;
+
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = (Vec<i64>, Vec<i64>);
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let values = line
+                    .split_ascii_whitespace()
+                    .map(|s| {
+                        s.parse::<i64>()
+                            .map_err(|e| ParseError::new(i, line, s, format!("cannot parse: {e}")))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                match &values[..] {
+                    &[left, right] => Ok((left, right)),
+                    _ => Err(ParseError::new(i, line, line, "expected two values").into()),
+                }
+            })
+            .collect()
+    }
+
+    fn part_one((left_values, right_values): &Self::Input) -> Result<i64> {
+        let mut left_values = left_values.clone();
+        let mut right_values = right_values.clone();
+        left_values.sort();
+        right_values.sort();
+        let r = std::iter::zip(left_values, right_values)
+            .map(|(left, right)| (left - right).abs())
+            .sum();
+        Ok(r)
+    }
+
+    fn part_two((left_values, right_values): &Self::Input) -> Result<i64> {
+        let right_value_counts = right_values
+            .iter()
+            .fold(HashMap::new(), |mut counts, value| {
+                counts
+                    .entry(*value)
+                    .and_modify(|counter| *counter += 1)
+                    .or_insert(1);
+                counts
+            });
+
+        let r = left_values
+            .iter()
+            .map(|left| left * right_value_counts.get(left).unwrap_or(&0))
+            .sum();
+        Ok(r)
+    }
+}
@@ -1,12 +1,9 @@
-use std::io::Read;
-
 use eyre::Result;
 
-fn read_input() -> Result<String> {
-    let mut s = String::new();
-    std::io::stdin().read_to_string(&mut s)?;
-    Ok(s)
-}
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day02;
 
 fn is_safe<I>(mut row: I) -> bool
 where
@@ -66,38 +63,47 @@ where
     .is_ok()
 }
 
-fn solve(input: String) -> i64 {
-    input
-        .lines()
-        .map(|line| {
-            line.split_ascii_whitespace()
-                .map(|s| s.parse().expect(&format!("cannot parse {s:?}")))
-        })
-        .filter_map(|row| {
-            let row: Vec<i64> = row.collect();
+impl Solution for Day02 {
+    type Input = Vec<Vec<i64>>;
+    type PartOne = usize;
+    type PartTwo = usize;
 
-            for delete_index in 0..row.len() {
-                let filtered_row = row
-                    .iter()
-                    .cloned()
-                    .enumerate()
-                    .filter_map(|(i, value)| (i != delete_index).then_some(value));
-                if is_safe(filtered_row) {
-                    return Some(());
-                }
-            }
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                line.split_ascii_whitespace()
+                    .map(|s| {
+                        s.parse()
+                            .map_err(|e| ParseError::new(i, line, s, format!("cannot parse: {e}")))
+                    })
+                    .collect::<Result<_, _>>()
+                    .map_err(Into::into)
+            })
+            .collect()
+    }
 
-            None
-        })
-        .count()
-        .try_into()
-        .expect("overflow")
-}
+    fn part_one(rows: &Self::Input) -> Result<usize> {
+        Ok(rows
+            .iter()
+            .filter(|row| is_safe(row.iter().cloned()))
+            .count())
+    }
 
-fn main() -> Result<()> {
-    color_eyre::install()?;
-    let input = read_input()?;
-    let answer = solve(input);
-    println!("{answer}");
-    Ok(())
+    fn part_two(rows: &Self::Input) -> Result<usize> {
+        Ok(rows
+            .iter()
+            .filter(|row| {
+                (0..row.len()).any(|delete_index| {
+                    let filtered_row = row
+                        .iter()
+                        .cloned()
+                        .enumerate()
+                        .filter_map(|(i, value)| (i != delete_index).then_some(value));
+                    is_safe(filtered_row)
+                })
+            })
+            .count())
+    }
 }
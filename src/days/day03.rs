@@ -0,0 +1,52 @@
+use eyre::Result;
+use regex::Regex;
+
+use crate::Solution;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = String;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(input: &Self::Input) -> Result<i64> {
+        let mul_regex = Regex::new(r"mul[(](?P<first>\d+),(?P<second>\d+)[)]").unwrap();
+        let mut result = 0;
+
+        for m in mul_regex.captures_iter(input) {
+            let first: i64 = m.name("first").unwrap().as_str().parse().unwrap();
+            let second: i64 = m.name("second").unwrap().as_str().parse().unwrap();
+            result += first * second;
+        }
+
+        Ok(result)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<i64> {
+        let mul_regex = Regex::new(
+            r"(?P<off>don't)|(?P<on>do)|(?P<op>mul[(](?P<first>\d+),(?P<second>\d+)[)])",
+        )
+        .unwrap();
+        let mut result = 0;
+        let mut on_off = true;
+
+        for m in mul_regex.captures_iter(input) {
+            if m.name("on").is_some() {
+                on_off = true;
+            } else if m.name("off").is_some() {
+                on_off = false;
+            } else if on_off && m.name("op").is_some() {
+                let first: i64 = m.name("first").unwrap().as_str().parse().unwrap();
+                let second: i64 = m.name("second").unwrap().as_str().parse().unwrap();
+                result += first * second;
+            }
+        }
+
+        Ok(result)
+    }
+}
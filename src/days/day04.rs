@@ -0,0 +1,132 @@
+use eyre::Result;
+use itertools::Itertools;
+use tracing::{debug, trace};
+
+use crate::geometry::{Dimension, Position};
+use crate::grid::Grid;
+use crate::Solution;
+
+pub struct Day04;
+
+fn get_lines(grid: &Grid<char>) -> [(&str, Vec<String>); 4] {
+    [
+        (
+            "rows",
+            grid.rows().map(|row| row.iter().collect()).collect(),
+        ),
+        (
+            "columns",
+            grid.columns().map(|column| column.collect()).collect(),
+        ),
+        (
+            "1st diagonals",
+            grid.diagonals()
+                .map(|diagonal| diagonal.collect())
+                .collect(),
+        ),
+        (
+            "2nd diagonals",
+            grid.anti_diagonals()
+                .map(|diagonal| diagonal.collect())
+                .collect(),
+        ),
+    ]
+}
+
+const PATTERN_TEMPLATE: &str = "
+    M.S
+    .A.
+    M.S
+";
+
+fn rotate_pattern(pattern: &Grid<char>) -> Grid<char> {
+    let Dimension { rows, columns } = pattern.dimension();
+    Grid::from_fn(
+        Dimension {
+            rows: columns,
+            columns: rows,
+        },
+        |Position { row, column }| {
+            pattern[Position {
+                row: rows - 1 - column,
+                column: row,
+            }]
+        },
+    )
+}
+
+fn get_patterns() -> Vec<Grid<char>> {
+    let initial_pattern: Grid<char> = PATTERN_TEMPLATE
+        .trim()
+        .lines()
+        .map(|line| line.trim())
+        .join("\n")
+        .parse()
+        .unwrap();
+    let mut patterns = vec![initial_pattern];
+    patterns.push(rotate_pattern(patterns.last().unwrap()));
+    patterns.push(rotate_pattern(patterns.last().unwrap()));
+    patterns.push(rotate_pattern(patterns.last().unwrap()));
+    patterns
+}
+
+fn does_pattern_match(grid: &Grid<char>, position: Position, pattern: &Grid<char>) -> bool {
+    pattern.iter().all(|(pattern_position, pattern_char)| {
+        *pattern_char == '.'
+            || grid.get(Position {
+                row: position.row + pattern_position.row,
+                column: position.column + pattern_position.column,
+            }) == Some(pattern_char)
+    })
+}
+
+fn find_pattern_matches<'a>(
+    grid: &'a Grid<char>,
+    pattern: &'a Grid<char>,
+) -> impl Iterator<Item = Position> + 'a {
+    grid.dimension()
+        .positions()
+        .filter(move |position| does_pattern_match(grid, *position, pattern))
+}
+
+impl Solution for Day04 {
+    type Input = Grid<char>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part_one(grid: &Self::Input) -> Result<usize> {
+        let search_word = "XMAS".to_string();
+        let reverse_search_word = {
+            let mut v = search_word.as_bytes().to_vec();
+            v.reverse();
+            String::from_utf8(v).unwrap()
+        };
+
+        debug!("input:\n{grid}");
+
+        Ok(get_lines(grid)
+            .into_iter()
+            .inspect(|(name, _lines)| debug!("scanning {name}"))
+            .flat_map(|(_name, lines)| lines)
+            .map(|line| {
+                let count = line.matches(&search_word).count();
+                let reverse_count = line.matches(&reverse_search_word).count();
+                trace!("{line} {count} {reverse_count}");
+                count + reverse_count
+            })
+            .sum())
+    }
+
+    fn part_two(grid: &Self::Input) -> Result<usize> {
+        let patterns = get_patterns();
+
+        Ok(patterns
+            .into_iter()
+            .map(|pattern| find_pattern_matches(grid, &pattern).count())
+            .sum())
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use eyre::Result;
+
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day05;
+
+#[derive(Debug)]
+pub struct Manual {
+    rules: HashMap<i64, HashSet<i64>>,
+    updates: Vec<Vec<i64>>,
+}
+
+// Return a pair of indices that are in the wrong order.
+fn find_mistake(rules: &HashMap<i64, HashSet<i64>>, update: &[i64]) -> Option<(usize, usize)> {
+    update.iter().enumerate().skip(1).find_map(|(i, x)| {
+        let are_before = &update[..i];
+        if let Some(must_after) = rules.get(x) {
+            are_before
+                .iter()
+                .enumerate()
+                .find(|(_j, y)| must_after.contains(y))
+                .map(|(j, _y)| (i, j))
+        } else {
+            None
+        }
+    })
+}
+
+fn is_valid(rules: &HashMap<i64, HashSet<i64>>, update: &[i64]) -> bool {
+    find_mistake(rules, update).is_none()
+}
+
+impl Solution for Day05 {
+    type Input = Manual;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines: Vec<_> = input.lines().collect();
+        let divider_index = lines
+            .iter()
+            .position(|s| s.is_empty())
+            .ok_or_else(|| eyre::eyre!("missing empty line between rules and updates"))?;
+        let parse_page = |i: usize, line: &str, s: &str| {
+            s.parse::<i64>()
+                .map_err(|e| ParseError::new(i, line, s, format!("cannot parse page: {e}")))
+        };
+        let rules: Vec<(i64, i64)> = lines[..divider_index]
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                let (a, b) = line
+                    .split_once('|')
+                    .ok_or_else(|| ParseError::new(i, line, line, "expected a rule like 47|53"))?;
+                Ok((parse_page(i, line, a)?, parse_page(i, line, b)?))
+            })
+            .collect::<Result<_, ParseError>>()?;
+        let rules: HashMap<i64, HashSet<i64>> =
+            rules.iter().fold(HashMap::new(), |mut m, (a, b)| {
+                m.entry(*a).or_default().insert(*b);
+                m
+            });
+        let updates: Vec<Vec<i64>> = lines
+            .iter()
+            .enumerate()
+            .skip(divider_index + 1)
+            .map(|(i, line)| line.split(',').map(|s| parse_page(i, line, s)).collect())
+            .collect::<Result<_, ParseError>>()?;
+        Ok(Manual { rules, updates })
+    }
+
+    fn part_one(Manual { rules, updates }: &Self::Input) -> Result<i64> {
+        Ok(updates
+            .iter()
+            .filter(|update| is_valid(rules, update))
+            .map(|update| update[update.len() / 2])
+            .sum())
+    }
+
+    fn part_two(Manual { rules, updates }: &Self::Input) -> Result<i64> {
+        Ok(updates
+            .iter()
+            .filter(|update| !is_valid(rules, update))
+            .map(|update| {
+                let mut update = update.to_vec();
+                while let Some((i, j)) = find_mistake(rules, &update) {
+                    update.swap(i, j);
+                }
+                update
+            })
+            .map(|update| update[update.len() / 2])
+            .sum())
+    }
+}
@@ -0,0 +1,388 @@
+use std::collections::{HashMap, HashSet};
+
+use eyre::Result;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::geometry::{Dimension, Direction, Position, Vec2};
+use crate::grid::Grid;
+use crate::image::Rgb;
+use crate::{Part, Solution};
+
+pub struct Day06;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tile {
+    Open,
+    Obstacle,
+}
+
+impl std::fmt::Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Tile::Open => write!(f, "."),
+            Tile::Obstacle => write!(f, "#"),
+        }
+    }
+}
+
+type Map = Grid<Tile>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Guard {
+    position: Position,
+    direction: Direction,
+}
+
+impl Guard {
+    fn step(&mut self, map: &Map) -> Option<()> {
+        let next_position = self.position.step(self.direction, map.dimension())?;
+
+        if map[next_position] == Tile::Obstacle {
+            self.direction = self.direction.turn_right();
+        } else {
+            self.position = next_position;
+        }
+
+        Some(())
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Map, Guard)> {
+    let mut maybe_guard = None;
+    let map = Map::parse_with(input, |position, c| {
+        Ok(match Direction::try_from(c) {
+            Ok(direction) => {
+                let old_guard = maybe_guard.replace(Guard {
+                    position,
+                    direction,
+                });
+                if let Some(old_guard) = old_guard {
+                    eyre::bail!("there already was a guard: {old_guard:?}");
+                }
+                Tile::Open
+            }
+            Err('#') => Tile::Obstacle,
+            Err('.') => Tile::Open,
+            Err(_) => eyre::bail!("unexpected character"),
+        })
+    })?;
+
+    match maybe_guard {
+        Some(guard) => Ok((map, guard)),
+        None => eyre::bail!("there was no guard!"),
+    }
+}
+
+// For every position and orthogonal direction, where a guard walking that way stops in front of an
+// obstacle, or `None` if it walks off the map. Indexed like `Direction::all4`.
+struct Jumps([Grid<Option<Position>>; 4]);
+
+// How many steps ahead of the guard `position` is, if it's straight ahead.
+fn steps_ahead(guard: &Guard, position: Position) -> Option<usize> {
+    let Vec2 { d_row, d_column } = position - guard.position;
+    let ahead = Vec2::from(guard.direction);
+    let steps = match (ahead.d_row, ahead.d_column) {
+        (0, d) => (d_row == 0).then_some(d_column * d)?,
+        (d, 0) => (d_column == 0).then_some(d_row * d)?,
+        _ => return None,
+    };
+    usize::try_from(steps).ok()
+}
+
+impl Jumps {
+    fn new(map: &Map) -> Self {
+        let dimension = map.dimension();
+        Self(Direction::all4().map(|direction| {
+            // Fill in the positions furthest along `direction` first, so the next position along
+            // is always done already.
+            let Vec2 { d_row, d_column } = direction.into();
+            let mut positions: Vec<Position> = dimension.positions().collect();
+            if d_row + d_column > 0 {
+                positions.reverse();
+            }
+
+            let mut stops = map.map(|_| None);
+            for position in positions {
+                stops[position] = match position.step(direction, dimension) {
+                    None => None,
+                    Some(next) if map[next] == Tile::Obstacle => Some(position),
+                    Some(next) => stops[next],
+                };
+            }
+            stops
+        }))
+    }
+
+    // Where the guard stops with an extra obstacle on the map.
+    fn stop(&self, guard: &Guard, obstacle: Position, dimension: Dimension) -> Option<Position> {
+        let stop = self.0[guard.direction as usize / 2][guard.position];
+        let to_stop = stop.and_then(|stop| steps_ahead(guard, stop));
+
+        match steps_ahead(guard, obstacle) {
+            Some(to_obstacle) if to_obstacle > 0 && to_stop.is_none_or(|s| to_obstacle <= s) => {
+                obstacle.step(guard.direction.opposite(), dimension)
+            }
+            _ => stop,
+        }
+    }
+}
+
+// Jump from turn to turn. The guard is in a loop once it turns at the same place twice.
+fn causes_loop(map: &Map, jumps: &Jumps, guard: &Guard, obstacle: Position) -> bool {
+    let mut guard = guard.clone();
+    let mut turns = HashSet::new();
+
+    while let Some(stop) = jumps.stop(&guard, obstacle, map.dimension()) {
+        guard = Guard {
+            position: stop,
+            direction: guard.direction.turn_right(),
+        };
+        if !turns.insert(guard.clone()) {
+            return true;
+        }
+    }
+
+    false
+}
+
+// Return the guard as it enters each position for the first time, starting with the initial
+// guard.
+fn patrol(map: &Map, guard: &Guard) -> Vec<Guard> {
+    let mut guard = guard.clone();
+    let mut visited_set = HashSet::new();
+    let mut visited_vec = Vec::new();
+    visited_set.insert(guard.position);
+    visited_vec.push(guard.clone());
+
+    std::iter::from_fn(|| {
+        guard.step(map)?;
+        Some(guard.clone())
+    })
+    .for_each(|guard| {
+        if visited_set.insert(guard.position) {
+            visited_vec.push(guard);
+        }
+    });
+
+    visited_vec
+}
+
+/// What a cell shows in a visualisation of the patrol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mark {
+    Open,
+    Obstacle,
+    /// The obstacle placed to trap the guard in a loop.
+    Candidate,
+    /// Walked through by the guard, vertically, horizontally or both.
+    Trail {
+        vertical: bool,
+        horizontal: bool,
+        in_loop: bool,
+    },
+    Guard(Direction),
+}
+
+const BOLD_RED: &str = "\x1b[1;31m";
+const BOLD_GREEN: &str = "\x1b[1;32m";
+const YELLOW: &str = "\x1b[33m";
+const RESET: &str = "\x1b[0m";
+
+// Draws the trail like the puzzle text does, in colour for the terminal.
+impl std::fmt::Display for Mark {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Mark::Open => write!(f, "."),
+            Mark::Obstacle => write!(f, "#"),
+            Mark::Candidate => write!(f, "{BOLD_RED}O{RESET}"),
+            Mark::Trail {
+                vertical,
+                horizontal,
+                in_loop,
+            } => {
+                let c = match (vertical, horizontal) {
+                    (true, true) => '+',
+                    (true, false) => '|',
+                    _ => '-',
+                };
+                if in_loop {
+                    write!(f, "{YELLOW}{c}{RESET}")
+                } else {
+                    write!(f, "{c}")
+                }
+            }
+            Mark::Guard(direction) => write!(f, "{BOLD_GREEN}{}{RESET}", char::from(direction)),
+        }
+    }
+}
+
+impl Mark {
+    /// The colour of the mark in pictures of the patrol.
+    pub fn colour(&self) -> Rgb {
+        match self {
+            Mark::Open => Rgb::grey(0.9),
+            Mark::Obstacle => Rgb::grey(0.2),
+            Mark::Candidate => Rgb(220, 30, 30),
+            Mark::Trail { in_loop: false, .. } => Rgb(90, 140, 230),
+            Mark::Trail { in_loop: true, .. } => Rgb(240, 170, 20),
+            Mark::Guard(_) => Rgb(20, 160, 60),
+        }
+    }
+}
+
+/// A frame of a visualisation of the patrol.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    pub caption: String,
+    pub marks: Grid<Mark>,
+}
+
+// Walk the guard across the map until it leaves or walks in a loop, calling `show` with every
+// frame that `every_step` or a turn asks for. Returns whether the guard loops.
+fn walk(
+    map: &Map,
+    guard: &Guard,
+    obstacle: Option<Position>,
+    every_step: bool,
+    caption: &dyn Fn(usize) -> String,
+    show: &mut dyn FnMut(&Frame) -> Result<()>,
+) -> Result<bool> {
+    let mut map = map.clone();
+    let mut marks = map.map(|tile| match tile {
+        Tile::Open => Mark::Open,
+        Tile::Obstacle => Mark::Obstacle,
+    });
+    if let Some(obstacle) = obstacle {
+        map[obstacle] = Tile::Obstacle;
+        marks[obstacle] = Mark::Candidate;
+    }
+
+    let mut guard = guard.clone();
+    let mut path: Vec<Guard> = Vec::new();
+    let mut seen = HashMap::new();
+    let frame = |marks: &Grid<Mark>, guard: &Guard, steps: usize| {
+        let mut marks = marks.clone();
+        marks[guard.position] = Mark::Guard(guard.direction);
+        Frame {
+            caption: caption(steps),
+            marks,
+        }
+    };
+
+    loop {
+        if let Some(&start) = seen.get(&guard) {
+            for looped in &path[start..] {
+                if let Mark::Trail { in_loop, .. } = &mut marks[looped.position] {
+                    *in_loop = true;
+                }
+            }
+            show(&frame(&marks, &guard, path.len()))?;
+            return Ok(true);
+        }
+        seen.insert(guard.clone(), path.len());
+        path.push(guard.clone());
+
+        let vertical = matches!(guard.direction, Direction::Up | Direction::Down);
+        marks[guard.position] = match marks[guard.position] {
+            Mark::Trail {
+                vertical: v,
+                horizontal: h,
+                in_loop,
+            } => Mark::Trail {
+                vertical: v || vertical,
+                horizontal: h || !vertical,
+                in_loop,
+            },
+            _ => Mark::Trail {
+                vertical,
+                horizontal: !vertical,
+                in_loop: false,
+            },
+        };
+
+        let direction = guard.direction;
+        if guard.step(&map).is_none() {
+            show(&frame(&marks, &guard, path.len()))?;
+            return Ok(false);
+        }
+        if every_step || guard.direction != direction {
+            show(&frame(&marks, &guard, path.len()))?;
+        }
+    }
+}
+
+/// Visualise the patrol, calling `show` with every frame. Part one shows every step of the patrol.
+/// Part two shows every obstacle that traps the guard in a loop, with a frame for every turn the
+/// guard takes and the loop highlighted at the end.
+pub fn visualise(
+    (map, guard): &(Grid<Tile>, Guard),
+    part: Part,
+    mut show: impl FnMut(&Frame) -> Result<()>,
+) -> Result<()> {
+    match part {
+        Part::One => {
+            walk(
+                map,
+                guard,
+                None,
+                true,
+                &|steps| format!("part a: step {steps}"),
+                &mut show,
+            )?;
+        }
+        Part::Two => {
+            let jumps = Jumps::new(map);
+            let obstacles: Vec<Position> = patrol(map, guard)
+                .windows(2)
+                .filter(|guards| causes_loop(map, &jumps, &guards[0], guards[1].position))
+                .map(|guards| guards[1].position)
+                .collect();
+
+            for (i, &obstacle) in obstacles.iter().enumerate() {
+                let caption = |steps| {
+                    format!(
+                        "part b: obstacle {} of {} at row {}, column {}: step {steps}",
+                        i + 1,
+                        obstacles.len(),
+                        obstacle.row,
+                        obstacle.column
+                    )
+                };
+                walk(map, guard, Some(obstacle), false, &caption, &mut show)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+impl Solution for Day06 {
+    type Input = (Map, Guard);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one((map, guard): &Self::Input) -> Result<usize> {
+        Ok(patrol(map, guard).len())
+    }
+
+    fn part_two((map, guard): &Self::Input) -> Result<usize> {
+        // The guard would notice an obstacle placed at the starting position, so only the
+        // positions entered afterwards are candidates. The path up to a candidate is unaffected by
+        // it, so each simulation can start where the guard entered the previous new position.
+        let jumps = Jumps::new(map);
+        let patrol = patrol(map, guard);
+
+        #[cfg(feature = "parallel")]
+        let candidates = patrol.par_windows(2);
+        #[cfg(not(feature = "parallel"))]
+        let candidates = patrol.windows(2);
+
+        Ok(candidates
+            .filter(|guards| causes_loop(map, &jumps, &guards[0], guards[1].position))
+            .count())
+    }
+}
@@ -0,0 +1,202 @@
+use eyre::Result;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day07;
+
+#[derive(Debug)]
+pub struct Equation {
+    result: i64,
+    operands: Vec<i64>,
+}
+
+fn concat(a: i64, b: i64) -> i64 {
+    a * (10i64).pow(b.max(1).ilog10() + 1) + b
+}
+
+#[test]
+fn test_concat() {
+    macro_rules! assert_concat {
+        ($a:expr, $b:expr, $c:expr) => {{
+            let actual = concat($a, $b);
+            let expected = $c;
+            assert!(
+                actual == expected,
+                "assertion failed: {} == {actual}, expected {expected}",
+                stringify!(concat($a, $b))
+            );
+        }};
+    }
+
+    assert_concat!(15, 6, 156);
+    assert_concat!(72, 90, 7290);
+    assert_concat!(100, 1, 1001);
+    assert_concat!(100, 0, 1000);
+    assert_concat!(0, 100, 100);
+    assert_concat!(0, 0, 0);
+}
+
+// The left operand that gives `result` when combined with `right` by concatenation, if any.
+fn unconcat(result: i64, right: i64) -> Option<i64> {
+    let shift = (10i64).pow(right.max(1).ilog10() + 1);
+    (result % shift == right).then(|| result / shift)
+}
+
+// What undoing an operator says about its left operand.
+enum Left {
+    Impossible,
+    Exactly(i64),
+    // Multiplying by zero gives zero whatever the left operand is.
+    Any,
+}
+
+// An operator with its inverse, which finds the left operand from the result and the right operand.
+type Operator = (&'static str, fn(i64, i64) -> i64, fn(i64, i64) -> Left);
+
+// Part one only uses the first two operators, part two uses all of them.
+const OPERATOR_CHOICES: &[Operator] = &[
+    ("+", <i64 as std::ops::Add>::add, |result, right| {
+        Left::Exactly(result - right)
+    }),
+    ("*", <i64 as std::ops::Mul>::mul, |result, right| {
+        match (result, right) {
+            (0, 0) => Left::Any,
+            (_, 0) => Left::Impossible,
+            _ if result % right == 0 => Left::Exactly(result / right),
+            _ => Left::Impossible,
+        }
+    }),
+    ("||", concat, |result, right| {
+        unconcat(result, right).map_or(Left::Impossible, Left::Exactly)
+    }),
+];
+
+// Operators are evaluated left to right, so the last operand is the one applied last. Undo it with
+// every operator that could have produced the result and recurse on the rest, which rules most
+// operators out early: a product has to be divisible, a concatenation has to end in the operand.
+// Operands are never negative, so neither is anything they produce.
+fn can_produce(operator_choices: &[Operator], operands: &[i64], result: i64) -> bool {
+    if result < 0 {
+        return false;
+    }
+    let Some((&last, rest)) = operands.split_last() else {
+        return false;
+    };
+    if rest.is_empty() {
+        return last == result;
+    }
+
+    operator_choices
+        .iter()
+        .any(|(_symbol, apply, undo)| match undo(result, last) {
+            Left::Impossible => false,
+            Left::Exactly(left) => {
+                debug_assert_eq!(apply(left, last), result);
+                can_produce(operator_choices, rest, left)
+            }
+            // The rest of the operands produce something, and that is all it takes.
+            Left::Any => true,
+        })
+}
+
+#[test]
+fn test_can_produce() {
+    use itertools::Itertools;
+
+    // Every result of evaluating `operands` left to right with any of the operators.
+    fn results(operator_choices: &[Operator], operands: &[i64]) -> Vec<i64> {
+        let Some((&last, rest)) = operands.split_last() else {
+            return Vec::new();
+        };
+        if rest.is_empty() {
+            return vec![last];
+        }
+        results(operator_choices, rest)
+            .into_iter()
+            .flat_map(|left| {
+                operator_choices
+                    .iter()
+                    .map(move |(_symbol, apply, _undo)| apply(left, last))
+            })
+            .collect()
+    }
+
+    assert!(can_produce(&OPERATOR_CHOICES[..2], &[5, 0, 3], 3));
+
+    for operator_choices in [&OPERATOR_CHOICES[..2], OPERATOR_CHOICES] {
+        for operands in
+            (1..=4).flat_map(|n| std::iter::repeat_n([0, 1, 2, 5, 10], n).multi_cartesian_product())
+        {
+            let produced = results(operator_choices, &operands);
+            for result in (-1..=30).chain(produced.iter().copied()) {
+                assert_eq!(
+                    can_produce(operator_choices, &operands, result),
+                    produced.contains(&result),
+                    "{result}: {operands:?}"
+                );
+            }
+        }
+    }
+}
+
+fn calibration_result(equations: &[Equation], operator_choices: &[Operator]) -> i64 {
+    #[cfg(feature = "parallel")]
+    let equations = equations.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let equations = equations.iter();
+
+    equations
+        .filter(|equation| can_produce(operator_choices, &equation.operands, equation.result))
+        .map(|equation| equation.result)
+        .sum()
+}
+
+impl Solution for Day07 {
+    type Input = Vec<Equation>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let parse_number = |s: &str| {
+                    s.parse::<i64>()
+                        .map_err(|e| ParseError::new(i, line, s, format!("cannot parse: {e}")))
+                };
+                let (result, operands) = line.split_once(':').ok_or_else(|| {
+                    ParseError::new(i, line, line, "expected an equation like 190: 10 19")
+                })?;
+                let result = parse_number(result)?;
+                let operands: Vec<_> = operands
+                    .split_ascii_whitespace()
+                    .map(|s| match parse_number(s)? {
+                        operand if operand < 0 => Err(ParseError::new(
+                            i,
+                            line,
+                            s,
+                            "expected a non-negative operand",
+                        )),
+                        operand => Ok(operand),
+                    })
+                    .collect::<Result<_, _>>()?;
+                if operands.is_empty() {
+                    return Err(ParseError::new(i, line, line, "expected operands").into());
+                }
+                Ok(Equation { result, operands })
+            })
+            .collect()
+    }
+
+    fn part_one(equations: &Self::Input) -> Result<i64> {
+        Ok(calibration_result(equations, &OPERATOR_CHOICES[..2]))
+    }
+
+    fn part_two(equations: &Self::Input) -> Result<i64> {
+        Ok(calibration_result(equations, OPERATOR_CHOICES))
+    }
+}
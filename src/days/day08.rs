@@ -0,0 +1,233 @@
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+
+use eyre::Result;
+use itertools::Itertools;
+use tracing::{debug, trace};
+
+use crate::geometry::{Dimension, Position};
+use crate::grid::Grid;
+use crate::image::Rgb;
+use crate::{Part, Solution};
+
+pub struct Day08;
+
+trait Antinodes {
+    fn antinodes(&self, dimension: Dimension) -> Vec<Position>;
+    fn resonant_antinodes(&self, dimension: Dimension) -> Vec<Position>;
+}
+
+impl<P> Antinodes for (P, P)
+where
+    P: Borrow<Position>,
+{
+    fn antinodes(&self, dimension: Dimension) -> Vec<Position> {
+        let (p1, p2) = self;
+        let (p1, p2) = (*p1.borrow(), *p2.borrow());
+        let mut antinodes = Vec::new();
+
+        let v = p2 - p1;
+
+        if let Some(a1) = p1 - v {
+            antinodes.push(a1);
+        }
+
+        if let Some(a2) = p2 + v {
+            antinodes.push(a2);
+        }
+
+        antinodes.retain(|a| dimension.contains(*a));
+        antinodes
+    }
+
+    fn resonant_antinodes(&self, dimension: Dimension) -> Vec<Position> {
+        let (p1, p2) = self;
+        let (p1, p2) = (*p1.borrow(), *p2.borrow());
+        let mut antinodes = vec![p1, p2];
+
+        let v = p2 - p1;
+
+        {
+            let mut a = p1;
+            antinodes.extend(
+                std::iter::from_fn(|| {
+                    a = (a - v)?;
+                    Some(a)
+                })
+                .take_while(|a| dimension.contains(*a)),
+            );
+        }
+
+        {
+            let mut a = p2;
+            antinodes.extend(
+                std::iter::from_fn(|| {
+                    a = (a + v)?;
+                    Some(a)
+                })
+                .take_while(|a| dimension.contains(*a)),
+            );
+        }
+
+        antinodes
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Frequency(char);
+
+impl TryFrom<char> for Frequency {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        if c.is_ascii_alphanumeric() {
+            Ok(Self(c))
+        } else {
+            Err(c)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Location {
+    Empty,
+    Antenna(Frequency),
+    Antinode,
+}
+
+impl std::fmt::Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Location::Empty => write!(f, "."),
+            Location::Antenna(Frequency(c)) => write!(f, "{c}"),
+            Location::Antinode => write!(f, "#"),
+        }
+    }
+}
+
+impl Location {
+    /// Antennas in a colour per frequency, antinodes in white.
+    pub fn colour(&self) -> Rgb {
+        match self {
+            Location::Empty => Rgb::grey(0.1),
+            Location::Antenna(Frequency(c)) => Rgb::distinct(*c as usize),
+            Location::Antinode => Rgb::WHITE,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    locations: Grid<Location>,
+    antennas: HashMap<Frequency, HashSet<Position>>,
+    antinodes: HashSet<Position>,
+}
+
+impl Map {
+    fn new(locations: Grid<Location>) -> Self {
+        let mut antennas: HashMap<Frequency, HashSet<Position>> = HashMap::new();
+
+        for (position, location) in locations.iter() {
+            if let Location::Antenna(f) = location {
+                antennas.entry(*f).or_default().insert(position);
+            }
+        }
+
+        Self {
+            locations,
+            antennas,
+            antinodes: HashSet::new(),
+        }
+    }
+
+    fn place_antinodes<F>(&mut self, antinodes: F)
+    where
+        F: Fn((&Position, &Position), Dimension) -> Vec<Position>,
+    {
+        for (_f, positions) in self.antennas.iter() {
+            for v in positions.iter().combinations(2) {
+                let &[p1, p2] = &v[..] else {
+                    unreachable!("{v:?}")
+                };
+
+                let antinodes = antinodes((p1, p2), self.locations.dimension());
+
+                trace!("antinodes for {p1:?}, {p2:?}: {antinodes:?}");
+
+                for position in antinodes {
+                    self.antinodes.insert(position);
+
+                    let location = &mut self.locations[position];
+                    if let Location::Empty = location {
+                        *location = Location::Antinode;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.locations)
+    }
+}
+
+fn with_antinodes<F>(map: &Map, antinodes: F) -> Map
+where
+    F: Fn((&Position, &Position), Dimension) -> Vec<Position>,
+{
+    let mut map = map.clone();
+
+    debug!("antennas:\n{map}");
+
+    map.place_antinodes(antinodes);
+
+    debug!("antinodes:\n{map}");
+
+    map
+}
+
+fn part_antinodes(part: Part) -> fn((&Position, &Position), Dimension) -> Vec<Position> {
+    match part {
+        Part::One => |pair, dimension| pair.antinodes(dimension),
+        Part::Two => |pair, dimension| pair.resonant_antinodes(dimension),
+    }
+}
+
+/// The map with the antinodes for `part` placed.
+pub fn antinode_map(map: &Map, part: Part) -> Grid<Location> {
+    with_antinodes(map, part_antinodes(part)).locations
+}
+
+impl Solution for Day08 {
+    type Input = Map;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let locations = Grid::parse_with(input, |_position, c| {
+            Ok(match Frequency::try_from(c) {
+                Ok(f) => Location::Antenna(f),
+                Err('.') | Err('#') => Location::Empty,
+                Err(_) => eyre::bail!("unexpected character"),
+            })
+        })?;
+
+        debug!(dimension = ?locations.dimension());
+
+        Ok(Map::new(locations))
+    }
+
+    fn part_one(map: &Self::Input) -> Result<usize> {
+        Ok(with_antinodes(map, part_antinodes(Part::One))
+            .antinodes
+            .len())
+    }
+
+    fn part_two(map: &Self::Input) -> Result<usize> {
+        Ok(with_antinodes(map, part_antinodes(Part::Two))
+            .antinodes
+            .len())
+    }
+}
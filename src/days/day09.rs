@@ -0,0 +1,611 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap};
+use std::io::{BufRead, Write};
+
+use eyre::Result;
+use tracing::trace;
+
+use crate::geometry::Dimension;
+use crate::grid::Grid;
+use crate::image::Rgb;
+use crate::parse::ParseError;
+use crate::{Part, Solution};
+
+pub struct Day09;
+
+#[derive(Debug, Clone)]
+struct Block {
+    id: usize,
+    size: u32,
+}
+
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
+struct Gap {
+    size: u32,
+}
+
+// The sizes of the blocks and the gaps between them, alternating, one byte each.
+#[derive(Debug, Clone)]
+pub struct DiskMap {
+    sizes: Vec<u8>,
+    total_size: u32,
+}
+
+impl DiskMap {
+    fn block_count(&self) -> usize {
+        self.sizes.len().div_ceil(2)
+    }
+
+    fn block_size(&self, id: usize) -> usize {
+        usize::from(self.sizes[2 * id])
+    }
+
+    // The gap after the block, the last block has none.
+    fn gap_size(&self, id: usize) -> usize {
+        self.sizes
+            .get(2 * id + 1)
+            .map_or(0, |&size| usize::from(size))
+    }
+
+    // The blocks and the gaps by position, for moving them around.
+    fn blocks_and_gaps(&self) -> (BTreeMap<u32, Block>, BTreeMap<u32, Gap>) {
+        let mut blocks = BTreeMap::new();
+        let mut gaps = BTreeMap::new();
+        let mut position = 0;
+
+        for (id, sizes) in self.sizes.chunks(2).enumerate() {
+            let size = u32::from(sizes[0]);
+            blocks.insert(position, Block { id, size });
+            position += size;
+
+            if let Some(&size) = sizes.get(1).filter(|&&size| size > 0) {
+                gaps.insert(position, Gap { size: size.into() });
+                position += u32::from(size);
+            }
+        }
+
+        (blocks, gaps)
+    }
+}
+
+// Where the reader is, for pointing parse errors at the input.
+struct ReadPosition<'a> {
+    sizes: &'a [u8],
+    line_index: usize,
+    // What follows the sizes on the first line, or the whole of a later line.
+    rest: &'a str,
+}
+
+impl ReadPosition<'_> {
+    fn error(&self, byte: u8, message: &str) -> ParseError {
+        let mut line = String::new();
+        if self.line_index == 0 {
+            line.extend(self.sizes.iter().map(|&size| char::from(b'0' + size)));
+        }
+        line.push_str(self.rest);
+        let column = line.chars().count();
+
+        let text = String::from_utf8_lossy(&[byte]).into_owned();
+        line.push_str(&text);
+        ParseError::at(self.line_index, &line, column, &text, message)
+    }
+}
+
+/// Read a disk map a buffer at a time, keeping one byte per size. Whitespace may only follow the
+/// sizes.
+pub fn read_disk_map(mut reader: impl BufRead) -> Result<DiskMap> {
+    let mut sizes = Vec::new();
+    let mut total_size: u32 = 0;
+    let mut line_index = 0;
+    let mut rest = String::new();
+
+    loop {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            break;
+        }
+
+        for &byte in buffer {
+            let position = || ReadPosition {
+                sizes: &sizes,
+                line_index,
+                rest: &rest,
+            };
+            match byte {
+                b'0'..=b'9' if line_index == 0 && rest.is_empty() => {
+                    let size = byte - b'0';
+                    if size == 0 && sizes.len().is_multiple_of(2) {
+                        return Err(position().error(byte, "empty block").into());
+                    }
+                    total_size = total_size
+                        .checked_add(size.into())
+                        .ok_or_else(|| position().error(byte, "the disk is too large"))?;
+                    sizes.push(size);
+                }
+                b'\n' => {
+                    line_index += 1;
+                    rest.clear();
+                }
+                b' ' | b'\t' | b'\r' => rest.push(char::from(byte)),
+                _ if line_index == 0 && rest.is_empty() => {
+                    return Err(position().error(byte, "expected a digit").into());
+                }
+                _ => {
+                    return Err(position()
+                        .error(byte, "expected nothing after the disk map")
+                        .into());
+                }
+            }
+        }
+
+        let length = buffer.len();
+        reader.consume(length);
+    }
+
+    Ok(DiskMap { sizes, total_size })
+}
+
+const CHARS: [char; 76] = [
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i',
+    'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B',
+    'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U',
+    'V', 'W', 'X', 'Y', 'Z', '!', '#', '$', '%', '&', '*', '+', '-', '/', ':', ';', '?', '@', '^',
+];
+
+const PREFIX: &str = "blocks: ";
+
+// How block ids are drawn: one character from CHARS per unit while there are few enough ids, and
+// the id in base 76 with as many characters per unit as the largest id needs otherwise.
+#[derive(Debug, Clone, Copy)]
+struct Palette {
+    width: usize,
+}
+
+impl Palette {
+    fn new(largest: usize) -> Self {
+        let mut width = 1;
+        let mut ids = CHARS.len();
+        while largest >= ids {
+            width += 1;
+            ids = ids.saturating_mul(CHARS.len());
+        }
+        Self { width }
+    }
+
+    fn unit(&self, mut id: usize) -> String {
+        let mut digits = vec!['0'; self.width];
+        for digit in digits.iter_mut().rev() {
+            *digit = CHARS[id % CHARS.len()];
+            id /= CHARS.len();
+        }
+        digits.into_iter().collect()
+    }
+}
+
+// Draw the disk with one character per unit, or more if the palette needs them.
+fn render_blocks(blocks: &BTreeMap<u32, Block>, total_size: u32, palette: Palette) -> String {
+    let mut s = PREFIX.to_string();
+    let mut end = 0;
+
+    for (&position, block) in blocks {
+        s.push_str(&".".repeat((position - end) as usize * palette.width));
+        s.push_str(&palette.unit(block.id).repeat(block.size as usize));
+        end = position + block.size;
+    }
+    s.push_str(&".".repeat(total_size.saturating_sub(end) as usize * palette.width));
+
+    s
+}
+
+// Number the positions of the disk vertically, one line per decimal digit, aligned with
+// render_blocks.
+fn render_ruler(total_size: u32, palette: Palette) -> String {
+    let prefix_width = PREFIX.len();
+    let width = total_size as usize * palette.width;
+    let border = format!("{empty:prefix_width$}{empty:#<width$}", empty = "");
+    let mut lines = vec![border.clone()];
+
+    for k in (0..=total_size.max(1).ilog10()).rev() {
+        let mut line = " ".repeat(prefix_width);
+
+        for i in 0..total_size {
+            let y = 10u32.pow(k);
+            let d = i / y;
+            let m = i % y;
+            let t = d % 10;
+
+            if m == 0 && (t != 0 || k == 0) {
+                line.push(char::from_digit(t, 10).unwrap());
+            } else {
+                line.push(' ');
+            }
+            line.push_str(&" ".repeat(palette.width - 1));
+        }
+
+        lines.push(line.trim_end().to_string());
+    }
+
+    lines.push(border);
+    lines.join("\n")
+}
+
+// A block, or part of one, moved into a gap.
+#[derive(Debug, Clone, Copy)]
+struct Move {
+    id: usize,
+    size: u32,
+    from: u32,
+    to: u32,
+}
+
+// Called with the blocks after every move.
+type OnMove<'a> = &'a mut dyn FnMut(&BTreeMap<u32, Block>, Move) -> Result<()>;
+
+// The checksum of `count` units of block `id` from `start` on. Checksums of disks of a few million
+// blocks don't fit in 64 bits.
+fn units_checksum(id: usize, start: usize, count: usize) -> u128 {
+    let (id, start, count) = (id as u128, start as u128, count as u128);
+    id * (count * start + count * count.saturating_sub(1) / 2)
+}
+
+fn checksum(blocks: &BTreeMap<u32, Block>) -> u128 {
+    blocks
+        .iter()
+        .map(|(&position, block)| units_checksum(block.id, position as usize, block.size as usize))
+        .sum()
+}
+
+// The checksum after moving single units, without moving anything: walk the blocks from the front
+// and fill the gap after each with units taken from the blocks at the back.
+fn compacted_checksum(disk_map: &DiskMap) -> u128 {
+    let Some(mut back) = disk_map.block_count().checked_sub(1) else {
+        return 0;
+    };
+    let mut back_left = disk_map.block_size(back);
+    let mut position = 0;
+    let mut checksum = 0;
+    let mut front = 0;
+
+    while front < back {
+        let size = disk_map.block_size(front);
+        checksum += units_checksum(front, position, size);
+        position += size;
+
+        let mut free = disk_map.gap_size(front);
+        while free > 0 {
+            if back_left == 0 {
+                back -= 1;
+                if back == front {
+                    break;
+                }
+                back_left = disk_map.block_size(back);
+            }
+
+            let count = free.min(back_left);
+            checksum += units_checksum(back, position, count);
+            position += count;
+            free -= count;
+            back_left -= count;
+        }
+
+        front += 1;
+    }
+
+    // What is left of the block at the back stays where it is.
+    if front == back {
+        checksum += units_checksum(back, position, back_left);
+    }
+
+    checksum
+}
+
+// Move single units of the last blocks into the first gaps until there are no gaps left between
+// the blocks.
+fn compact_units(disk_map: &DiskMap, on_move: OnMove) -> Result<BTreeMap<u32, Block>> {
+    let (mut blocks, mut gaps) = disk_map.blocks_and_gaps();
+
+    // Units move one at a time from the last block into the first gap, so a run of them can
+    // move at once: as many as both the block and the gap have.
+    while let (Some(mut gap_entry), Some(mut block_entry)) =
+        (gaps.first_entry(), blocks.last_entry())
+    {
+        let gap_position = *gap_entry.key();
+        let block_position = *block_entry.key();
+
+        if gap_position > block_position {
+            break;
+        }
+
+        let id = block_entry.get().id;
+        let size = gap_entry.get().size.min(block_entry.get().size);
+        // The units at the end of the block move.
+        let from = block_position + block_entry.get().size - size;
+
+        trace!("moving {size} units of block {id} from {block_position} to {gap_position}");
+
+        block_entry.get_mut().size -= size;
+        if block_entry.get().size == 0 {
+            block_entry.remove();
+        }
+
+        gap_entry.get_mut().size -= size;
+        let gap = gap_entry.remove();
+        if gap.size > 0 {
+            gaps.insert(gap_position + size, gap);
+        }
+
+        blocks.insert(gap_position, Block { id, size });
+        on_move(
+            &blocks,
+            Move {
+                id,
+                size,
+                from,
+                to: gap_position,
+            },
+        )?;
+    }
+
+    Ok(blocks)
+}
+
+// Sizes come from single digits.
+const MAX_SIZE: u32 = 9;
+
+// The positions of the gaps of every size from 1 up, lowest first, indexed by size - 1.
+struct GapHeaps([BinaryHeap<Reverse<u32>>; MAX_SIZE as usize]);
+
+impl GapHeaps {
+    fn new(gaps: &BTreeMap<u32, Gap>) -> Self {
+        let mut heaps = Self(Default::default());
+        for (&position, gap) in gaps {
+            heaps.insert(position, gap.size);
+        }
+        heaps
+    }
+
+    fn insert(&mut self, position: u32, size: u32) {
+        self.0[size as usize - 1].push(Reverse(position));
+    }
+
+    // Remove the first gap that fits `size` units, if it comes before `limit`. Returns its position
+    // and size.
+    fn take_first(&mut self, size: u32, limit: u32) -> Option<(u32, u32)> {
+        let (gap_size, position) = (size..=MAX_SIZE)
+            .filter_map(|gap_size| {
+                let &Reverse(position) = self.0[gap_size as usize - 1].peek()?;
+                Some((gap_size, position))
+            })
+            .filter(|&(_, position)| position < limit)
+            .min_by_key(|&(_, position)| position)?;
+
+        self.0[gap_size as usize - 1].pop();
+        Some((position, gap_size))
+    }
+}
+
+// Move every block, last first, into the first gap before it that is large enough.
+fn compact_files(disk_map: &DiskMap, on_move: OnMove) -> Result<BTreeMap<u32, Block>> {
+    let (mut blocks, gaps) = disk_map.blocks_and_gaps();
+    let mut gaps = GapHeaps::new(&gaps);
+
+    fn position_and_size((position, block): (&u32, &Block)) -> (u32, u32) {
+        (*position, block.size)
+    }
+
+    let mut maybe_next_block_position_and_size = blocks.iter().map(position_and_size).next_back();
+
+    while let Some((block_position, block_size)) = maybe_next_block_position_and_size {
+        trace!(
+            "position: {block_position}, block {}",
+            blocks[&block_position].id
+        );
+
+        let Some((gap_position, gap_size)) = gaps.take_first(block_size, block_position) else {
+            trace!("no gap");
+            maybe_next_block_position_and_size = blocks
+                .range(..block_position)
+                .map(position_and_size)
+                .next_back();
+            continue;
+        };
+
+        trace!("gap position: {gap_position}");
+
+        let block = blocks.remove(&block_position).unwrap();
+        let id = block.id;
+        if blocks.insert(gap_position, block).is_some() {
+            unreachable!("already a block at this position!?");
+        }
+
+        if gap_size > block_size {
+            gaps.insert(gap_position + block_size, gap_size - block_size);
+        }
+
+        on_move(
+            &blocks,
+            Move {
+                id,
+                size: block_size,
+                from: block_position,
+                to: gap_position,
+            },
+        )?;
+
+        maybe_next_block_position_and_size = blocks
+            .range(..block_position)
+            .map(position_and_size)
+            .next_back();
+    }
+
+    Ok(blocks)
+}
+
+fn compact(disk_map: &DiskMap, part: Part, on_move: OnMove) -> Result<BTreeMap<u32, Block>> {
+    match part {
+        Part::One => compact_units(disk_map, on_move),
+        Part::Two => compact_files(disk_map, on_move),
+    }
+}
+
+/// Write the disk with a ruler above it, then the disk again after every `every` moves of
+/// compacting it for `part`, and once it is compacted.
+pub fn trace(disk_map: &DiskMap, part: Part, every: usize, out: &mut dyn Write) -> Result<()> {
+    let palette = Palette::new(disk_map.block_count().saturating_sub(1));
+    let total_size = disk_map.total_size;
+    let (blocks, _gaps) = disk_map.blocks_and_gaps();
+
+    writeln!(out, "{}", render_ruler(total_size, palette))?;
+    writeln!(out, "{}", render_blocks(&blocks, total_size, palette))?;
+
+    let mut moves = 0usize;
+    let mut shown = true;
+    let blocks = compact(
+        disk_map,
+        part,
+        &mut |blocks, Move { id, size, from, to }| {
+            moves += 1;
+            shown = moves.is_multiple_of(every);
+            if shown {
+                writeln!(
+                    out,
+                    "move {moves}: {size} units of block {id} from {from} to {to}"
+                )?;
+                writeln!(out, "{}", render_blocks(blocks, total_size, palette))?;
+            }
+            Ok(())
+        },
+    )?;
+
+    if !shown {
+        writeln!(out, "compacted after {moves} moves")?;
+        writeln!(out, "{}", render_blocks(&blocks, total_size, palette))?;
+    }
+    Ok(())
+}
+
+/// The disk after compacting it for `part`, wrapped into a square with the id of the block in every
+/// unit.
+pub fn disk_layout(disk_map: &DiskMap, part: Part) -> Result<Grid<Option<usize>>> {
+    let blocks = compact(disk_map, part, &mut |_, _| Ok(()))?;
+
+    let mut units = vec![None; disk_map.total_size as usize];
+    for (&position, block) in &blocks {
+        let position = position as usize;
+        units[position..position + block.size as usize].fill(Some(block.id));
+    }
+
+    let columns = (units.len() as f64).sqrt().ceil().max(1.0) as usize;
+    let dimension = Dimension {
+        rows: units.len().div_ceil(columns),
+        columns,
+    };
+    Ok(Grid::from_fn(dimension, |position| {
+        units
+            .get(position.row * columns + position.column)
+            .copied()
+            .flatten()
+    }))
+}
+
+/// Colour the units of a disk layout by block id, free space is black. The colours repeat, GIF
+/// frames can't have more than 256.
+pub fn colour(unit: &Option<usize>) -> Rgb {
+    unit.map_or(Rgb::BLACK, |id| Rgb::distinct(id % 255))
+}
+
+impl Solution for Day09 {
+    type Input = DiskMap;
+    type PartOne = u128;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        read_disk_map(input.as_bytes())
+    }
+
+    fn part_one(disk_map: &Self::Input) -> Result<u128> {
+        Ok(compacted_checksum(disk_map))
+    }
+
+    fn part_two(disk_map: &Self::Input) -> Result<u128> {
+        Ok(checksum(&compact(disk_map, Part::Two, &mut |_, _| Ok(()))?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_palette() {
+        assert_eq!(Palette::new(75).width, 1);
+        let palette = Palette::new(76);
+        assert_eq!(palette.width, 2);
+        assert_eq!(palette.unit(76), "10");
+        assert_eq!(palette.unit(5), "05");
+
+        let blocks = BTreeMap::from([
+            (0, Block { id: 76, size: 2 }),
+            (3, Block { id: 1, size: 1 }),
+        ]);
+        let blocks = render_blocks(&blocks, 6, palette);
+        assert_eq!(blocks, "blocks: 1010..01....");
+
+        let border = format!("{:8}{}", "", "#".repeat(12));
+        assert_eq!(border.len(), blocks.len());
+        assert_eq!(
+            render_ruler(6, palette),
+            format!("{border}\n        0 1 2 3 4 5\n{border}")
+        );
+    }
+
+    #[test]
+    fn test_read_disk_map() {
+        let read = |input: &str, capacity| {
+            read_disk_map(std::io::BufReader::with_capacity(
+                capacity,
+                input.as_bytes(),
+            ))
+            .map_err(|e| {
+                let e = e.downcast::<ParseError>().unwrap();
+                (e.line, e.column, e.message)
+            })
+        };
+
+        let whole = read_disk_map("2333133121414131402\n \n".as_bytes()).unwrap();
+        let errors = [
+            ("12a4", (1, 3, "expected a digit")),
+            ("12 3", (1, 4, "expected nothing after the disk map")),
+            ("1234\n  x", (2, 3, "expected nothing after the disk map")),
+        ];
+        for capacity in [1, 3, 4096] {
+            let streamed = read("2333133121414131402\n \n", capacity).unwrap();
+            assert_eq!(streamed.sizes, whole.sizes);
+            assert_eq!(streamed.total_size, whole.total_size);
+
+            for (input, (line, column, message)) in errors {
+                assert_eq!(
+                    read(input, capacity).unwrap_err(),
+                    (line, column, message.to_string()),
+                    "{input:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_large_checksum() {
+        // A million blocks of 9 units with nothing between them, so nothing moves and block `id`
+        // adds id * (81 * id + 36).
+        let blocks = 1_000_000;
+        let disk_map = Day09::parse(&("90".repeat(blocks - 1) + "9")).unwrap();
+        assert_eq!(
+            Day09::part_one(&disk_map).unwrap(),
+            26_999_977_499_995_500_000
+        );
+        assert_eq!(
+            Day09::part_two(&disk_map).unwrap(),
+            26_999_977_499_995_500_000
+        );
+    }
+}
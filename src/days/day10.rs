@@ -0,0 +1,327 @@
+use std::ops::RangeInclusive;
+
+use eyre::Result;
+use tracing::debug;
+
+use crate::geometry::Position;
+use crate::grid::Grid;
+use crate::image::Rgb;
+use crate::search::bfs;
+use crate::Solution;
+
+pub struct Day10;
+
+/// Which neighbouring positions a trail can move to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Up, down, left and right.
+    Four,
+    /// Diagonally as well.
+    Eight,
+}
+
+/// The rules hiking trails follow. The default rules are the puzzle's.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrailRules {
+    /// The height of trail heads.
+    pub start: u32,
+    /// The height of peaks, where trails end.
+    pub end: u32,
+    /// How much the height goes up with every step.
+    pub steps: RangeInclusive<u32>,
+    pub connectivity: Connectivity,
+}
+
+impl Default for TrailRules {
+    fn default() -> Self {
+        Self {
+            start: 0,
+            end: 9,
+            steps: 1..=1,
+            connectivity: Connectivity::Four,
+        }
+    }
+}
+
+impl TrailRules {
+    // Trails have to go up at every step, otherwise they could go round in circles.
+    fn check(&self) -> Result<()> {
+        eyre::ensure!(
+            self.start < self.end,
+            "trails must end higher than they start, not at {} from {}",
+            self.end,
+            self.start
+        );
+        eyre::ensure!(
+            !self.steps.is_empty() && *self.steps.start() > 0,
+            "trails must go up at every step, not by {:?}",
+            self.steps
+        );
+        Ok(())
+    }
+
+    fn neighbours<'a>(
+        &self,
+        map: &'a Grid<Option<u32>>,
+        position: Position,
+    ) -> Box<dyn Iterator<Item = Position> + 'a> {
+        match self.connectivity {
+            Connectivity::Four => Box::new(map.neighbours4(position)),
+            Connectivity::Eight => Box::new(map.neighbours8(position)),
+        }
+    }
+
+    // The positions a trail at `position` can step up to.
+    fn uphill<'a>(
+        &'a self,
+        map: &'a Grid<Option<u32>>,
+        position: Position,
+    ) -> impl Iterator<Item = Position> + 'a {
+        let height = map[position];
+        self.neighbours(map, position)
+            .filter(move |&next| match (height, map[next]) {
+                (Some(height), Some(next)) => {
+                    next <= self.end
+                        && next
+                            .checked_sub(height)
+                            .is_some_and(|step| self.steps.contains(&step))
+                }
+                _ => false,
+            })
+    }
+}
+
+/// What the trail heads of a map add up to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Summary {
+    pub trail_heads: usize,
+    /// The sum of the number of peaks every trail head reaches.
+    pub score: usize,
+    /// The sum of the number of distinct trails from every trail head.
+    pub rating: usize,
+}
+
+// A set of peaks, one bit per peak in the order they appear on the map.
+#[derive(Debug, Clone, Default)]
+struct Peaks(Vec<u64>);
+
+impl Peaks {
+    fn single(peak: usize) -> Self {
+        let mut words = vec![0; peak / 64 + 1];
+        words[peak / 64] = 1 << (peak % 64);
+        Self(words)
+    }
+
+    fn union_with(&mut self, other: &Self) {
+        if self.0.len() < other.0.len() {
+            self.0.resize(other.0.len(), 0);
+        }
+        for (word, other) in self.0.iter_mut().zip(&other.0) {
+            *word |= other;
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.0.iter().map(|word| word.count_ones() as usize).sum()
+    }
+}
+
+// The peaks every position can reach on a hiking trail, and the number of distinct trails from it.
+#[derive(Debug)]
+struct Trails {
+    peaks: Grid<Peaks>,
+    ratings: Grid<usize>,
+}
+
+// Work down from the peaks: the trails from a position are the trails from the positions one step
+// up from it, so every position is only looked at once. That leaves no work per trail head for the
+// `parallel` feature to spread out, and every height depends on the heights above it.
+fn trails(map: &Grid<Option<u32>>, rules: &TrailRules) -> Result<Trails> {
+    rules.check()?;
+
+    let mut peaks = map.map(|_| Peaks::default());
+    let mut ratings = map.map(|_| 0);
+
+    let mut positions: Vec<(Position, u32)> = map
+        .iter()
+        .filter_map(|(position, &height)| Some((position, height?)))
+        .filter(|(_, height)| (rules.start..=rules.end).contains(height))
+        .collect();
+    positions.sort_by_key(|&(_, height)| std::cmp::Reverse(height));
+
+    let mut peak_count = 0;
+    for (position, height) in positions {
+        if height == rules.end {
+            peaks[position] = Peaks::single(peak_count);
+            ratings[position] = 1;
+            peak_count += 1;
+            continue;
+        }
+
+        let mut reachable = Peaks::default();
+        for next in rules.uphill(map, position) {
+            reachable.union_with(&peaks[next]);
+            ratings[position] += ratings[next];
+        }
+        peaks[position] = reachable;
+    }
+
+    Ok(Trails { peaks, ratings })
+}
+
+fn trail_heads<'a>(
+    map: &'a Grid<Option<u32>>,
+    rules: &'a TrailRules,
+) -> impl Iterator<Item = Position> + 'a {
+    map.iter()
+        .filter_map(|(position, &height)| (height == Some(rules.start)).then_some(position))
+}
+
+/// Score and rate every trail head of the map under the given rules.
+pub fn summarise(map: &Grid<Option<u32>>, rules: &TrailRules) -> Result<Summary> {
+    let trails = trails(map, rules)?;
+    let mut summary = Summary {
+        trail_heads: 0,
+        score: 0,
+        rating: 0,
+    };
+
+    for trail_head in trail_heads(map, rules) {
+        let (score, rating) = (trails.peaks[trail_head].len(), trails.ratings[trail_head]);
+        debug!(?trail_head, score, rating);
+        summary.trail_heads += 1;
+        summary.score += score;
+        summary.rating += rating;
+    }
+
+    debug!("trail heads: {}", summary.trail_heads);
+
+    Ok(summary)
+}
+
+/// A position on a picture of the trail network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Spot {
+    pub height: Option<u32>,
+    /// Whether the position is on a trail from a trail head to a peak.
+    pub on_trail: bool,
+}
+
+impl Spot {
+    /// Trails in green and the rest in grey, both lighter the higher they are. Impassable positions
+    /// are black.
+    pub fn colour(&self, rules: &TrailRules) -> Rgb {
+        let Some(height) = self.height else {
+            return Rgb::BLACK;
+        };
+        let brightness = 0.2 + 0.8 * f64::from(height.min(rules.end)) / f64::from(rules.end);
+        if self.on_trail {
+            Rgb(0, (brightness * 255.0) as u8, (brightness * 90.0) as u8)
+        } else {
+            Rgb::grey(brightness * 0.6)
+        }
+    }
+}
+
+/// Mark every position that is on a trail: reachable from a trail head and with a peak above it.
+pub fn trail_network(map: &Grid<Option<u32>>, rules: &TrailRules) -> Result<Grid<Spot>> {
+    let trails = trails(map, rules)?;
+    let leads_to_peak = |position: &Position| trails.ratings[*position] > 0;
+
+    let mut spots = map.map(|&height| Spot {
+        height,
+        on_trail: false,
+    });
+    let network = bfs(
+        trail_heads(map, rules).filter(leads_to_peak),
+        |&position| rules.uphill(map, position).filter(leads_to_peak),
+        map.map(|_| false),
+    );
+    for reached in network {
+        spots[reached.node].on_trail = true;
+    }
+
+    Ok(spots)
+}
+
+impl Solution for Day10 {
+    type Input = Grid<Option<u32>>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    // Anything but a digit is impassable, the examples use `.` for that.
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse_with(input, |_position, c| Ok(c.to_digit(10)))
+    }
+
+    fn part_one(map: &Self::Input) -> Result<usize> {
+        Ok(summarise(map, &TrailRules::default())?.score)
+    }
+
+    fn part_two(map: &Self::Input) -> Result<usize> {
+        Ok(summarise(map, &TrailRules::default())?.rating)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trail_rules() {
+        // Only diagonal steps lead from the trail head to the middle and on to the corners.
+        let map = Day10::parse("0.2\n.1.\n2.2").unwrap();
+        let rules = TrailRules {
+            end: 2,
+            ..TrailRules::default()
+        };
+        assert_eq!(
+            summarise(&map, &rules).unwrap(),
+            Summary {
+                trail_heads: 1,
+                score: 0,
+                rating: 0
+            }
+        );
+        let diagonal = TrailRules {
+            connectivity: Connectivity::Eight,
+            ..rules
+        };
+        assert_eq!(
+            summarise(&map, &diagonal).unwrap(),
+            Summary {
+                trail_heads: 1,
+                score: 3,
+                rating: 3
+            }
+        );
+
+        // 1 2 4, 1 2 4 and 1 3 4 both peaks, going up by one or two.
+        let map = Day10::parse("124\n34.").unwrap();
+        let rules = TrailRules {
+            start: 1,
+            end: 4,
+            steps: 1..=2,
+            connectivity: Connectivity::Four,
+        };
+        assert_eq!(
+            summarise(&map, &rules).unwrap(),
+            Summary {
+                trail_heads: 1,
+                score: 2,
+                rating: 3
+            }
+        );
+
+        let level = TrailRules {
+            start: 4,
+            ..rules.clone()
+        };
+        assert!(summarise(&map, &level).is_err());
+        let flat = TrailRules {
+            steps: 0..=1,
+            ..rules
+        };
+        assert!(summarise(&map, &flat).is_err());
+    }
+}
@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use eyre::Result;
+
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day11;
+
+// Return the stones that a stone turns into after one blink.
+fn blink(stone: u64) -> (u64, Option<u64>) {
+    if stone == 0 {
+        return (1, None);
+    }
+
+    let digits = stone.ilog10() + 1;
+    if digits.is_multiple_of(2) {
+        let half = 10u64.pow(digits / 2);
+        (stone / half, Some(stone % half))
+    } else {
+        (stone * 2024, None)
+    }
+}
+
+// The stones don't affect each other, so the number of stones one stone turns into only depends on
+// its number and the blinks left. Most stones have small numbers that keep coming back.
+fn count_stones(stone: u64, blinks: u32, memo: &mut HashMap<(u64, u32), usize>) -> usize {
+    if blinks == 0 {
+        return 1;
+    }
+
+    if let Some(&count) = memo.get(&(stone, blinks)) {
+        return count;
+    }
+
+    let (left, right) = blink(stone);
+    let count = count_stones(left, blinks - 1, memo)
+        + right.map_or(0, |right| count_stones(right, blinks - 1, memo));
+
+    memo.insert((stone, blinks), count);
+    count
+}
+
+fn total_stones(stones: &[u64], blinks: u32) -> usize {
+    let mut memo = HashMap::new();
+    stones
+        .iter()
+        .map(|&stone| count_stones(stone, blinks, &mut memo))
+        .sum()
+}
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .enumerate()
+            .flat_map(|(i, line)| {
+                line.split_ascii_whitespace().map(move |s| {
+                    s.parse().map_err(|e| {
+                        ParseError::new(i, line, s, format!("cannot parse: {e}")).into()
+                    })
+                })
+            })
+            .collect()
+    }
+
+    fn part_one(stones: &Self::Input) -> Result<usize> {
+        Ok(total_stones(stones, 25))
+    }
+
+    fn part_two(stones: &Self::Input) -> Result<usize> {
+        Ok(total_stones(stones, 75))
+    }
+}
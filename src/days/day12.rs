@@ -0,0 +1,103 @@
+use eyre::Result;
+
+use crate::geometry::{Direction, Position, Vec2};
+use crate::grid::Grid;
+use crate::Solution;
+
+pub struct Day12;
+
+#[derive(Debug)]
+struct Region {
+    plant: char,
+    positions: Vec<Position>,
+}
+
+fn find_regions(garden: &Grid<char>) -> Vec<Region> {
+    let mut visited = garden.map(|_| false);
+    let mut regions = Vec::new();
+
+    for (start, &plant) in garden.iter() {
+        if visited[start] {
+            continue;
+        }
+
+        visited[start] = true;
+        let mut positions = vec![start];
+        let mut i = 0;
+
+        while let Some(&position) = positions.get(i) {
+            i += 1;
+            for neighbour in garden.neighbours4(position) {
+                if garden[neighbour] == plant && !visited[neighbour] {
+                    visited[neighbour] = true;
+                    positions.push(neighbour);
+                }
+            }
+        }
+
+        regions.push(Region { plant, positions });
+    }
+
+    regions
+}
+
+fn is_plant(garden: &Grid<char>, position: Option<Position>, plant: char) -> bool {
+    position.and_then(|position| garden.get(position)) == Some(&plant)
+}
+
+fn perimeter(garden: &Grid<char>, region: &Region) -> usize {
+    region
+        .positions
+        .iter()
+        .flat_map(|&position| {
+            Direction::all4().into_iter().filter(move |&direction| {
+                !is_plant(garden, position.offset(direction.into()), region.plant)
+            })
+        })
+        .count()
+}
+
+// A polygon has as many sides as corners. Every cell contributes the corners between each pair of
+// adjacent orthogonal directions: an outer corner where both neighbours are outside the region, and
+// an inner corner where both are inside but the diagonal between them is not.
+fn sides(garden: &Grid<char>, region: &Region) -> usize {
+    region
+        .positions
+        .iter()
+        .flat_map(|&position| {
+            Direction::all4().into_iter().filter(move |&direction| {
+                let right = direction.turn_right();
+                let plant = region.plant;
+                let a = is_plant(garden, position.offset(direction.into()), plant);
+                let b = is_plant(garden, position.offset(right.into()), plant);
+                let diagonal = Vec2::from(direction) + Vec2::from(right);
+                let c = is_plant(garden, position.offset(diagonal), plant);
+                (!a && !b) || (a && b && !c)
+            })
+        })
+        .count()
+}
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part_one(garden: &Self::Input) -> Result<usize> {
+        Ok(find_regions(garden)
+            .iter()
+            .map(|region| region.positions.len() * perimeter(garden, region))
+            .sum())
+    }
+
+    fn part_two(garden: &Self::Input) -> Result<usize> {
+        Ok(find_regions(garden)
+            .iter()
+            .map(|region| region.positions.len() * sides(garden, region))
+            .sum())
+    }
+}
@@ -0,0 +1,124 @@
+use eyre::Result;
+use regex::Regex;
+
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day13;
+
+#[derive(Debug, Clone, Copy)]
+struct Xy {
+    x: i64,
+    y: i64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Machine {
+    a: Xy,
+    b: Xy,
+    prize: Xy,
+}
+
+const PRIZE_OFFSET: i64 = 10_000_000_000_000;
+
+impl Machine {
+    // Solve a * A + b * B = prize for the presses a and b with Cramer's rule. The puzzle inputs
+    // never have parallel buttons, so there is at most one solution.
+    fn presses(&self) -> Option<(i64, i64)> {
+        let Machine { a, b, prize } = *self;
+        let determinant = a.x * b.y - a.y * b.x;
+        if determinant == 0 {
+            return None;
+        }
+
+        let a_numerator = prize.x * b.y - prize.y * b.x;
+        let b_numerator = a.x * prize.y - a.y * prize.x;
+        if a_numerator % determinant != 0 || b_numerator % determinant != 0 {
+            return None;
+        }
+
+        let presses = (a_numerator / determinant, b_numerator / determinant);
+        (presses.0 >= 0 && presses.1 >= 0).then_some(presses)
+    }
+}
+
+fn tokens((a, b): (i64, i64)) -> i64 {
+    3 * a + b
+}
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let line_regex =
+            Regex::new(r"^(?P<label>Button A|Button B|Prize): X[+=](?P<x>\d+), Y[+=](?P<y>\d+)$")
+                .unwrap();
+        let labels = ["Button A", "Button B", "Prize"];
+
+        let mut machines = Vec::new();
+        let mut values = Vec::new();
+
+        for (i, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+
+            let expected = labels[values.len()];
+            let captures = line_regex
+                .captures(line)
+                .filter(|captures| &captures["label"] == expected)
+                .ok_or_else(|| {
+                    ParseError::new(
+                        i,
+                        line,
+                        line,
+                        format!("expected a line like {expected}: X+1, Y+2"),
+                    )
+                })?;
+            let number = |name| {
+                let m = captures.name(name).unwrap();
+                m.as_str()
+                    .parse()
+                    .map_err(|e| ParseError::new(i, line, m.as_str(), format!("cannot parse: {e}")))
+            };
+            values.push(Xy {
+                x: number("x")?,
+                y: number("y")?,
+            });
+
+            if let &[a, b, prize] = &values[..] {
+                machines.push(Machine { a, b, prize });
+                values.clear();
+            }
+        }
+
+        eyre::ensure!(values.is_empty(), "the last machine is incomplete");
+        Ok(machines)
+    }
+
+    fn part_one(machines: &Self::Input) -> Result<i64> {
+        Ok(machines
+            .iter()
+            .filter_map(Machine::presses)
+            .filter(|&(a, b)| a <= 100 && b <= 100)
+            .map(tokens)
+            .sum())
+    }
+
+    fn part_two(machines: &Self::Input) -> Result<i64> {
+        Ok(machines
+            .iter()
+            .map(|machine| Machine {
+                prize: Xy {
+                    x: machine.prize.x + PRIZE_OFFSET,
+                    y: machine.prize.y + PRIZE_OFFSET,
+                },
+                ..*machine
+            })
+            .filter_map(|machine| machine.presses())
+            .map(tokens)
+            .sum())
+    }
+}
@@ -0,0 +1,175 @@
+use eyre::Result;
+use regex::Regex;
+use tracing::debug;
+
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day14;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Robot {
+    x: i64,
+    y: i64,
+    vx: i64,
+    vy: i64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Space {
+    width: i64,
+    height: i64,
+}
+
+// The size of the space is only given in the puzzle text, the example's is 11 by 7.
+const SPACE: Space = Space {
+    width: 101,
+    height: 103,
+};
+
+impl Space {
+    fn from_params(params: &Params) -> Result<Self> {
+        params.check_names(&["width", "height"])?;
+        let space = Space {
+            width: params.get("width", SPACE.width)?,
+            height: params.get("height", SPACE.height)?,
+        };
+        eyre::ensure!(
+            space.width > 0 && space.height > 0,
+            "the space cannot be {}x{}",
+            space.width,
+            space.height
+        );
+        Ok(space)
+    }
+}
+
+#[derive(Debug)]
+pub struct Robots {
+    robots: Vec<Robot>,
+    space: Space,
+}
+
+impl Robot {
+    fn after(self, seconds: i64, space: Space) -> (i64, i64) {
+        (
+            (self.x + self.vx * seconds).rem_euclid(space.width),
+            (self.y + self.vy * seconds).rem_euclid(space.height),
+        )
+    }
+}
+
+fn safety_factor(robots: &[Robot], seconds: i64, space: Space) -> usize {
+    let (middle_x, middle_y) = (space.width / 2, space.height / 2);
+    let mut quadrants = [0; 4];
+
+    for robot in robots {
+        let (x, y) = robot.after(seconds, space);
+        if x == middle_x || y == middle_y {
+            continue;
+        }
+        quadrants[usize::from(x > middle_x) + 2 * usize::from(y > middle_y)] += 1;
+    }
+
+    quadrants.iter().product()
+}
+
+fn variance(values: impl Iterator<Item = i64> + Clone) -> f64 {
+    let n = values.clone().count() as f64;
+    let mean = values.clone().sum::<i64>() as f64 / n;
+    values.map(|v| (v as f64 - mean).powi(2)).sum::<f64>() / n
+}
+
+// The robots cluster into the picture in both directions at once. The x coordinates repeat every
+// `width` seconds and the y coordinates every `height` seconds, so find the most clustered time in
+// each direction separately and combine them with the Chinese remainder theorem.
+fn find_tree(robots: &[Robot], space: Space) -> i64 {
+    let most_clustered = |period: i64, coordinate: fn((i64, i64)) -> i64| {
+        (0..period)
+            .min_by(|&a, &b| {
+                let spread =
+                    |t| variance(robots.iter().map(move |r| coordinate(r.after(t, space))));
+                spread(a).total_cmp(&spread(b))
+            })
+            .unwrap()
+    };
+
+    let tx = most_clustered(space.width, |(x, _)| x);
+    let ty = most_clustered(space.height, |(_, y)| y);
+
+    (0..space.height)
+        .map(|k| tx + k * space.width)
+        .find(|t| t % space.height == ty)
+        .unwrap()
+}
+
+fn render(robots: &[Robot], seconds: i64, space: Space) -> String {
+    let mut cells = vec![vec!['.'; space.width as usize]; space.height as usize];
+    for robot in robots {
+        let (x, y) = robot.after(seconds, space);
+        cells[y as usize][x as usize] = '#';
+    }
+    cells
+        .into_iter()
+        .map(|row| row.into_iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Solution for Day14 {
+    type Input = Robots;
+    type PartOne = usize;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let space = Space::from_params(params)?;
+        let robot_regex =
+            Regex::new(r"^p=(?P<x>\d+),(?P<y>\d+) v=(?P<vx>-?\d+),(?P<vy>-?\d+)$").unwrap();
+
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let captures = robot_regex.captures(line).ok_or_else(|| {
+                    ParseError::new(i, line, line, "expected a robot like p=0,4 v=3,-3")
+                })?;
+                let number = |name: &str| {
+                    let s = captures.name(name).unwrap().as_str();
+                    s.parse()
+                        .map_err(|e| ParseError::new(i, line, s, format!("cannot parse: {e}")))
+                };
+                let robot = Robot {
+                    x: number("x")?,
+                    y: number("y")?,
+                    vx: number("vx")?,
+                    vy: number("vy")?,
+                };
+                if robot.x >= space.width || robot.y >= space.height {
+                    let message = format!("outside the {}x{} space", space.width, space.height);
+                    return Err(ParseError::new(i, line, line, message).into());
+                }
+                Ok(robot)
+            })
+            .collect::<Result<_>>()
+            .map(|robots| Robots { robots, space })
+    }
+
+    fn part_one(Robots { robots, space }: &Self::Input) -> Result<usize> {
+        Ok(safety_factor(robots, 100, *space))
+    }
+
+    fn part_two(Robots { robots, space }: &Self::Input) -> Result<i64> {
+        let space = *space;
+        let seconds = find_tree(robots, space);
+        debug!(
+            "after {seconds} seconds:\n{}",
+            render(robots, seconds, space)
+        );
+        Ok(seconds)
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use eyre::Result;
+use tracing::debug;
+
+use crate::geometry::{Dimension, Direction, Position};
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day15;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Empty,
+    Wall,
+    Box,
+    BoxLeft,
+    BoxRight,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Tile::Empty => write!(f, "."),
+            Tile::Wall => write!(f, "#"),
+            Tile::Box => write!(f, "O"),
+            Tile::BoxLeft => write!(f, "["),
+            Tile::BoxRight => write!(f, "]"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Warehouse {
+    map: Grid<Tile>,
+    robot: Position,
+    moves: Vec<Direction>,
+}
+
+impl Warehouse {
+    // Every tile becomes two tiles side by side, a box becomes one wide box.
+    fn widen(&self) -> Self {
+        let dimension = self.map.dimension();
+        let map = Grid::from_fn(
+            Dimension {
+                rows: dimension.rows,
+                columns: 2 * dimension.columns,
+            },
+            |Position { row, column }| match (
+                self.map[Position {
+                    row,
+                    column: column / 2,
+                }],
+                column % 2,
+            ) {
+                (Tile::Box, 0) => Tile::BoxLeft,
+                (Tile::Box, _) => Tile::BoxRight,
+                (tile, _) => tile,
+            },
+        );
+
+        Self {
+            map,
+            robot: Position {
+                row: self.robot.row,
+                column: 2 * self.robot.column,
+            },
+            moves: self.moves.clone(),
+        }
+    }
+
+    // Push everything in front of the robot, including whatever the halves of wide boxes push,
+    // unless any of it would hit a wall.
+    fn step(&mut self, direction: Direction) {
+        let dimension = self.map.dimension();
+        let mut pushed = vec![self.robot];
+        let mut seen = HashSet::from([self.robot]);
+        let mut i = 0;
+
+        while let Some(&position) = pushed.get(i) {
+            i += 1;
+            let Some(next) = position.step(direction, dimension) else {
+                return;
+            };
+
+            let mut push = |position: Position| {
+                if seen.insert(position) {
+                    pushed.push(position);
+                }
+            };
+
+            match self.map[next] {
+                Tile::Empty => (),
+                Tile::Wall => return,
+                Tile::Box => push(next),
+                Tile::BoxLeft => {
+                    push(next);
+                    push(Position {
+                        column: next.column + 1,
+                        ..next
+                    });
+                }
+                Tile::BoxRight => {
+                    push(next);
+                    push(Position {
+                        column: next.column - 1,
+                        ..next
+                    });
+                }
+            }
+        }
+
+        // Positions are found in order of distance from the robot, so move the farthest first.
+        for &position in pushed.iter().rev() {
+            let next = position.step(direction, dimension).unwrap();
+            self.map[next] = self.map[position];
+            self.map[position] = Tile::Empty;
+        }
+
+        self.robot = self.robot.step(direction, dimension).unwrap();
+    }
+}
+
+fn gps_sum(warehouse: &Warehouse) -> usize {
+    let mut warehouse = warehouse.clone();
+
+    for &direction in &warehouse.moves.clone() {
+        warehouse.step(direction);
+    }
+
+    debug!("after all moves:\n{}", warehouse.map);
+
+    warehouse
+        .map
+        .iter()
+        .filter(|(_, tile)| matches!(tile, Tile::Box | Tile::BoxLeft))
+        .map(|(position, _)| 100 * position.row + position.column)
+        .sum()
+}
+
+impl Solution for Day15 {
+    type Input = Warehouse;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let Some((map, moves)) = input.split_once("\n\n") else {
+            eyre::bail!("expected the map and the moves separated by an empty line");
+        };
+
+        let mut robot = None;
+        let map = Grid::parse_with(map, |position, c| {
+            Ok(match c {
+                '.' => Tile::Empty,
+                '#' => Tile::Wall,
+                'O' => Tile::Box,
+                '@' => {
+                    eyre::ensure!(robot.is_none(), "there is more than one robot");
+                    robot = Some(position);
+                    Tile::Empty
+                }
+                _ => eyre::bail!("unexpected character"),
+            })
+        })?;
+        let robot = robot.ok_or_else(|| eyre::eyre!("there is no robot"))?;
+
+        let first_line = map.dimension().rows + 1;
+        let moves = moves
+            .lines()
+            .enumerate()
+            .flat_map(|(i, line)| {
+                line.chars().enumerate().map(move |(column, c)| {
+                    Direction::try_from(c).map_err(|c| {
+                        let text = c.to_string();
+                        ParseError::at(first_line + i, line, column, &text, "expected a move")
+                    })
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Warehouse { map, robot, moves })
+    }
+
+    fn part_one(warehouse: &Self::Input) -> Result<usize> {
+        Ok(gps_sum(warehouse))
+    }
+
+    fn part_two(warehouse: &Self::Input) -> Result<usize> {
+        Ok(gps_sum(&warehouse.widen()))
+    }
+}
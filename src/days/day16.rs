@@ -0,0 +1,112 @@
+use std::collections::{HashMap, HashSet};
+
+use eyre::Result;
+
+use crate::geometry::{Direction, Position};
+use crate::grid::Grid;
+use crate::search::dijkstra;
+use crate::Solution;
+
+pub struct Day16;
+
+#[derive(Debug)]
+pub struct Maze {
+    walls: Grid<bool>,
+    start: Position,
+    end: Position,
+}
+
+type State = (Position, Direction);
+
+const STEP_COST: u64 = 1;
+const TURN_COST: u64 = 1000;
+
+// Dijkstra over positions and facings, from any of the `starts`. Returns the lowest score of every
+// reachable state.
+fn scores(maze: &Maze, starts: &[State]) -> HashMap<State, u64> {
+    let moves = |&(position, direction): &State| {
+        let ahead = position
+            .step(direction, maze.walls.dimension())
+            .filter(|&ahead| !maze.walls[ahead])
+            .map(|ahead| ((ahead, direction), STEP_COST));
+        let turns = [direction.turn_left(), direction.turn_right()]
+            .map(|turned| ((position, turned), TURN_COST));
+        ahead.into_iter().chain(turns)
+    };
+
+    dijkstra(starts.iter().copied(), moves, HashSet::new())
+        .map(|reached| (reached.node, reached.cost))
+        .collect()
+}
+
+fn best_score(maze: &Maze, scores: &HashMap<State, u64>) -> Result<u64> {
+    Direction::all4()
+        .into_iter()
+        .filter_map(|direction| scores.get(&(maze.end, direction)).copied())
+        .min()
+        .ok_or_else(|| eyre::eyre!("the end cannot be reached"))
+}
+
+impl Solution for Day16 {
+    type Input = Maze;
+    type PartOne = u64;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut start = None;
+        let mut end = None;
+
+        let walls = Grid::parse_with(input, |position, c| {
+            Ok(match c {
+                '#' => true,
+                '.' => false,
+                'S' => {
+                    eyre::ensure!(start.is_none(), "there is more than one start");
+                    start = Some(position);
+                    false
+                }
+                'E' => {
+                    eyre::ensure!(end.is_none(), "there is more than one end");
+                    end = Some(position);
+                    false
+                }
+                _ => eyre::bail!("unexpected character"),
+            })
+        })?;
+
+        Ok(Maze {
+            walls,
+            start: start.ok_or_else(|| eyre::eyre!("there is no start"))?,
+            end: end.ok_or_else(|| eyre::eyre!("there is no end"))?,
+        })
+    }
+
+    fn part_one(maze: &Self::Input) -> Result<u64> {
+        let scores = scores(maze, &[(maze.start, Direction::Right)]);
+        best_score(maze, &scores)
+    }
+
+    // A tile is on a best path if the best score to reach it plus the best score from it to the end
+    // is the best score. Searching backwards from the end, facing away from it, gives the latter.
+    fn part_two(maze: &Self::Input) -> Result<usize> {
+        let from_start = scores(maze, &[(maze.start, Direction::Right)]);
+        let best = best_score(maze, &from_start)?;
+
+        let from_end = scores(
+            maze,
+            &Direction::all4().map(|direction| (maze.end, direction)),
+        );
+
+        let tiles: HashSet<Position> = from_start
+            .iter()
+            .filter(|&(&(position, direction), score)| {
+                from_end
+                    .get(&(position, direction.opposite()))
+                    .is_some_and(|rest| score + rest == best)
+            })
+            .map(|(&(position, _), _)| position)
+            .collect();
+
+        Ok(tiles.len())
+    }
+}
@@ -0,0 +1,143 @@
+use eyre::Result;
+use itertools::Itertools;
+
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day17;
+
+#[derive(Debug, Clone)]
+pub struct Computer {
+    registers: [u64; 3],
+    program: Vec<u8>,
+}
+
+fn combo(operand: u8, [a, b, c]: [u64; 3]) -> Result<u64> {
+    match operand {
+        0..=3 => Ok(u64::from(operand)),
+        4 => Ok(a),
+        5 => Ok(b),
+        6 => Ok(c),
+        _ => eyre::bail!("combo operand {operand} is reserved"),
+    }
+}
+
+// The division instructions, which divide by a power of two.
+fn shift(value: u64, by: u64) -> u64 {
+    u32::try_from(by)
+        .ok()
+        .and_then(|by| value.checked_shr(by))
+        .unwrap_or(0)
+}
+
+// Run the program with the given registers and return its output.
+fn run(program: &[u8], [mut a, mut b, mut c]: [u64; 3]) -> Result<Vec<u8>> {
+    let mut output = Vec::new();
+    let mut pointer = 0;
+
+    while let (Some(&opcode), Some(&operand)) = (program.get(pointer), program.get(pointer + 1)) {
+        let literal = u64::from(operand);
+        pointer += 2;
+
+        match opcode {
+            0 => a = shift(a, combo(operand, [a, b, c])?),
+            1 => b ^= literal,
+            2 => b = combo(operand, [a, b, c])? % 8,
+            3 if a != 0 => pointer = usize::from(operand),
+            3 => (),
+            4 => b ^= c,
+            5 => output.push((combo(operand, [a, b, c])? % 8) as u8),
+            6 => b = shift(a, combo(operand, [a, b, c])?),
+            7 => c = shift(a, combo(operand, [a, b, c])?),
+            _ => eyre::bail!("unknown opcode {opcode} at {}", pointer - 2),
+        }
+    }
+
+    Ok(output)
+}
+
+// The programs print A % 8, or something derived from it, and shift A right by 3 until it is 0. So
+// the last output only depends on the highest 3 bits of A, the one before on the highest 6 bits and
+// so on. Build A from the highest bits down, trying the lowest digits first.
+fn find_quine(computer: &Computer, a: u64, matched: usize) -> Result<Option<u64>> {
+    let Computer {
+        registers: [_, b, c],
+        program,
+    } = computer;
+
+    if matched == program.len() {
+        return Ok(Some(a));
+    }
+
+    for digit in 0..8 {
+        let candidate = a << 3 | digit;
+        let output = run(program, [candidate, *b, *c])?;
+        if output == program[program.len() - matched - 1..] {
+            if let Some(a) = find_quine(computer, candidate, matched + 1)? {
+                return Ok(Some(a));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+impl Solution for Day17 {
+    type Input = Computer;
+    type PartOne = String;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut registers = [0; 3];
+        let mut program = None;
+
+        for (i, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(values) = line.strip_prefix("Program: ") {
+                program = Some(
+                    values
+                        .split(',')
+                        .map(|s| match s.parse() {
+                            Ok(n) if n < 8 => Ok(n),
+                            _ => Err(ParseError::new(i, line, s, "expected a 3-bit number")),
+                        })
+                        .collect::<Result<Vec<_>, _>>()?,
+                );
+                continue;
+            }
+
+            let Some((name, value)) = line
+                .strip_prefix("Register ")
+                .and_then(|line| line.split_once(": "))
+            else {
+                return Err(
+                    ParseError::new(i, line, line, "expected a register or the program").into(),
+                );
+            };
+            let index = ["A", "B", "C"]
+                .iter()
+                .position(|n| *n == name)
+                .ok_or_else(|| ParseError::new(i, line, name, "unknown register"))?;
+            registers[index] = value
+                .parse()
+                .map_err(|e| ParseError::new(i, line, value, format!("cannot parse: {e}")))?;
+        }
+
+        Ok(Computer {
+            registers,
+            program: program.ok_or_else(|| eyre::eyre!("there is no program"))?,
+        })
+    }
+
+    fn part_one(computer: &Self::Input) -> Result<String> {
+        Ok(run(&computer.program, computer.registers)?.iter().join(","))
+    }
+
+    fn part_two(computer: &Self::Input) -> Result<u64> {
+        find_quine(computer, 0, 0)?
+            .ok_or_else(|| eyre::eyre!("no value of register A makes the program print itself"))
+    }
+}
@@ -0,0 +1,124 @@
+use eyre::Result;
+
+use crate::geometry::{Dimension, Position};
+use crate::grid::Grid;
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::search::bfs;
+use crate::Solution;
+
+pub struct Day18;
+
+#[derive(Debug, Clone, Copy)]
+struct MemorySpace {
+    size: usize,
+    fallen: usize,
+}
+
+// The size of the memory space and how many bytes fall for part one are only given in the puzzle
+// text, the example's are 7 and 12.
+const MEMORY_SPACE: MemorySpace = MemorySpace {
+    size: 71,
+    fallen: 1024,
+};
+
+impl MemorySpace {
+    fn from_params(params: &Params) -> Result<Self> {
+        params.check_names(&["size", "fallen"])?;
+        let space = MemorySpace {
+            size: params.get("size", MEMORY_SPACE.size)?,
+            fallen: params.get("fallen", MEMORY_SPACE.fallen)?,
+        };
+        eyre::ensure!(space.size > 0, "the memory space cannot be empty");
+        Ok(space)
+    }
+}
+
+#[derive(Debug)]
+pub struct Bytes {
+    bytes: Vec<Position>,
+    space: MemorySpace,
+}
+
+// The fewest steps from the top left to the bottom right corner after the first `fallen` bytes.
+fn shortest_path(bytes: &[Position], size: usize, fallen: usize) -> Option<usize> {
+    let dimension = Dimension {
+        rows: size,
+        columns: size,
+    };
+    let mut corrupted = Grid::from_fn(dimension, |_| false);
+    for &byte in &bytes[..fallen] {
+        if let Some(cell) = corrupted.get_mut(byte) {
+            *cell = true;
+        }
+    }
+
+    let start = Position { row: 0, column: 0 };
+    let exit = Position {
+        row: size - 1,
+        column: size - 1,
+    };
+
+    let open = |&position: &Position| {
+        corrupted
+            .neighbours4(position)
+            .filter(|&neighbour| !corrupted[neighbour])
+    };
+    let visited = corrupted.map(|_| false);
+
+    bfs([start], open, visited)
+        .find(|reached| reached.node == exit)
+        .map(|reached| reached.cost)
+}
+
+impl Solution for Day18 {
+    type Input = Bytes;
+    type PartOne = usize;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let space = MemorySpace::from_params(params)?;
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let (x, y) = line
+                    .split_once(',')
+                    .ok_or_else(|| ParseError::new(i, line, line, "expected a byte like 5,4"))?;
+                let parse_number = |s: &str| {
+                    s.parse()
+                        .map_err(|e| ParseError::new(i, line, s, format!("cannot parse: {e}")))
+                };
+                Ok(Position {
+                    row: parse_number(y)?,
+                    column: parse_number(x)?,
+                })
+            })
+            .collect::<Result<_>>()
+            .map(|bytes| Bytes { bytes, space })
+    }
+
+    fn part_one(Bytes { bytes, space }: &Self::Input) -> Result<usize> {
+        let MemorySpace { size, fallen } = *space;
+        eyre::ensure!(bytes.len() >= fallen, "expected at least {fallen} bytes");
+        shortest_path(bytes, size, fallen).ok_or_else(|| eyre::eyre!("the exit cannot be reached"))
+    }
+
+    // Binary search for the number of fallen bytes that first cuts off the exit.
+    fn part_two(Bytes { bytes, space }: &Self::Input) -> Result<String> {
+        let MemorySpace { size, .. } = *space;
+        let blocked = (0..=bytes.len())
+            .collect::<Vec<_>>()
+            .partition_point(|&fallen| shortest_path(bytes, size, fallen).is_some());
+        eyre::ensure!(blocked > 0, "the exit cannot be reached at all");
+
+        let byte = bytes
+            .get(blocked - 1)
+            .ok_or_else(|| eyre::eyre!("the exit can still be reached after all bytes fell"))?;
+        Ok(format!("{},{}", byte.column, byte.row))
+    }
+}
@@ -0,0 +1,80 @@
+use eyre::Result;
+
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day19;
+
+#[derive(Debug)]
+pub struct Onsen {
+    towels: Vec<String>,
+    designs: Vec<String>,
+}
+
+// The number of ways to arrange towels into the design, counted from the end: the ways to make
+// the rest of the design from every position.
+fn arrangements(towels: &[String], design: &str) -> u64 {
+    let mut ways = vec![0; design.len() + 1];
+    ways[design.len()] = 1;
+
+    for start in (0..design.len()).rev() {
+        ways[start] = towels
+            .iter()
+            .filter(|towel| design[start..].starts_with(towel.as_str()))
+            .map(|towel| ways[start + towel.len()])
+            .sum();
+    }
+
+    ways[0]
+}
+
+impl Solution for Day19 {
+    type Input = Onsen;
+    type PartOne = usize;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut lines = input.lines().enumerate();
+
+        let Some((_, towels)) = lines.next() else {
+            eyre::bail!("the input is empty");
+        };
+        let towels = towels.split(", ").map(str::to_string).collect();
+
+        if let Some((i, line)) = lines.next().filter(|(_, line)| !line.is_empty()) {
+            return Err(
+                ParseError::new(i, line, line, "expected an empty line after the towels").into(),
+            );
+        }
+
+        let designs = lines
+            .map(
+                |(i, line)| match line.char_indices().find(|(_, c)| !"wubrg".contains(*c)) {
+                    Some((offset, c)) => {
+                        let text = &line[offset..offset + c.len_utf8()];
+                        Err(ParseError::new(i, line, text, "unknown colour"))
+                    }
+                    None => Ok(line.to_string()),
+                },
+            )
+            .collect::<Result<_, _>>()?;
+
+        Ok(Onsen { towels, designs })
+    }
+
+    fn part_one(onsen: &Self::Input) -> Result<usize> {
+        Ok(onsen
+            .designs
+            .iter()
+            .filter(|design| arrangements(&onsen.towels, design) > 0)
+            .count())
+    }
+
+    fn part_two(onsen: &Self::Input) -> Result<u64> {
+        Ok(onsen
+            .designs
+            .iter()
+            .map(|design| arrangements(&onsen.towels, design))
+            .sum())
+    }
+}
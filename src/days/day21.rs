@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+
+use eyre::Result;
+
+use crate::geometry::Position;
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day21;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Keypad {
+    Numeric,
+    Directional,
+}
+
+impl Keypad {
+    // The rows of keys, with a space for the gap.
+    fn rows(self) -> &'static [&'static str] {
+        match self {
+            Keypad::Numeric => &["789", "456", "123", " 0A"],
+            Keypad::Directional => &[" ^A", "<v>"],
+        }
+    }
+
+    fn position(self, key: char) -> Position {
+        self.rows()
+            .iter()
+            .enumerate()
+            .find_map(|(row, keys)| keys.find(key).map(|column| Position { row, column }))
+            .unwrap_or_else(|| panic!("no key {key:?} on the {self:?} keypad"))
+    }
+}
+
+// The shortest ways to move from one key to another and press it. Mixing horizontal and vertical
+// moves only makes the robots further up travel more, so there are at most two candidates: all
+// horizontal moves first or all vertical moves first, unless that passes over the gap.
+fn moves(keypad: Keypad, from: char, to: char) -> Vec<String> {
+    let from = keypad.position(from);
+    let to = keypad.position(to);
+    let gap = keypad.position(' ');
+
+    let horizontal =
+        if to.column > from.column { ">" } else { "<" }.repeat(from.column.abs_diff(to.column));
+    let vertical = if to.row > from.row { "v" } else { "^" }.repeat(from.row.abs_diff(to.row));
+
+    let mut moves = Vec::new();
+    if (Position {
+        row: from.row,
+        column: to.column,
+    }) != gap
+    {
+        moves.push(format!("{horizontal}{vertical}A"));
+    }
+    if (Position {
+        row: to.row,
+        column: from.column,
+    }) != gap
+    {
+        moves.push(format!("{vertical}{horizontal}A"));
+    }
+    moves.dedup();
+    moves
+}
+
+// Every robot starts and ends each press at A, so the presses needed for one key only depend on
+// the previous key and the number of directional keypads between it and the human.
+struct Presses {
+    memo: HashMap<(Keypad, char, char, usize), u64>,
+}
+
+impl Presses {
+    fn sequence(&mut self, keypad: Keypad, sequence: &str, robots: usize) -> u64 {
+        std::iter::once('A')
+            .chain(sequence.chars())
+            .zip(sequence.chars())
+            .map(|(from, to)| self.key(keypad, from, to, robots))
+            .sum()
+    }
+
+    fn key(&mut self, keypad: Keypad, from: char, to: char, robots: usize) -> u64 {
+        if let Some(&presses) = self.memo.get(&(keypad, from, to, robots)) {
+            return presses;
+        }
+
+        let presses = moves(keypad, from, to)
+            .iter()
+            .map(|moves| match robots {
+                0 => moves.len() as u64,
+                _ => self.sequence(Keypad::Directional, moves, robots - 1),
+            })
+            .min()
+            .unwrap();
+
+        self.memo.insert((keypad, from, to, robots), presses);
+        presses
+    }
+}
+
+fn complexities(codes: &[String], robots: usize) -> u64 {
+    let mut presses = Presses {
+        memo: HashMap::new(),
+    };
+
+    codes
+        .iter()
+        .map(|code| {
+            let numeric: u64 = code.trim_end_matches('A').parse().unwrap_or(0);
+            presses.sequence(Keypad::Numeric, code, robots) * numeric
+        })
+        .sum()
+}
+
+impl Solution for Day21 {
+    type Input = Vec<String>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                match line
+                    .char_indices()
+                    .find(|(_, c)| !"0123456789A".contains(*c))
+                {
+                    Some((offset, c)) => {
+                        let text = &line[offset..offset + c.len_utf8()];
+                        Err(
+                            ParseError::new(i, line, text, "not a key on the numeric keypad")
+                                .into(),
+                        )
+                    }
+                    None => Ok(line.to_string()),
+                }
+            })
+            .collect()
+    }
+
+    fn part_one(codes: &Self::Input) -> Result<u64> {
+        Ok(complexities(codes, 2))
+    }
+
+    fn part_two(codes: &Self::Input) -> Result<u64> {
+        Ok(complexities(codes, 25))
+    }
+}
@@ -0,0 +1,75 @@
+use eyre::Result;
+
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day22;
+
+const PRUNE: u64 = 16_777_216;
+
+fn next_secret(secret: u64) -> u64 {
+    let secret = (secret ^ (secret * 64)) % PRUNE;
+    let secret = (secret ^ (secret / 32)) % PRUNE;
+    (secret ^ (secret * 2048)) % PRUNE
+}
+
+fn secrets(initial: u64) -> impl Iterator<Item = u64> {
+    std::iter::successors(Some(initial), |&secret| Some(next_secret(secret)))
+}
+
+// Price changes are between -9 and 9, so four of them make a number in base 19.
+const SEQUENCES: usize = 19usize.pow(4);
+
+fn push_change(sequence: usize, change: i64) -> usize {
+    (sequence * 19 + (change + 9) as usize) % SEQUENCES
+}
+
+impl Solution for Day22 {
+    type Input = Vec<u64>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                line.parse().map_err(|e| {
+                    ParseError::new(i, line, line, format!("cannot parse: {e}")).into()
+                })
+            })
+            .collect()
+    }
+
+    fn part_one(buyers: &Self::Input) -> Result<u64> {
+        Ok(buyers
+            .iter()
+            .map(|&initial| secrets(initial).nth(2000).unwrap())
+            .sum())
+    }
+
+    // Every buyer sells at the first occurrence of the sequence of changes, add up those prices
+    // for every sequence at once.
+    fn part_two(buyers: &Self::Input) -> Result<u64> {
+        let mut bananas = vec![0; SEQUENCES];
+        let mut last_buyer = vec![usize::MAX; SEQUENCES];
+
+        for (buyer, &initial) in buyers.iter().enumerate() {
+            let prices: Vec<i64> = secrets(initial)
+                .take(2001)
+                .map(|s| (s % 10) as i64)
+                .collect();
+            let mut sequence = 0;
+
+            for (i, window) in prices.windows(2).enumerate() {
+                sequence = push_change(sequence, window[1] - window[0]);
+                if i >= 3 && last_buyer[sequence] != buyer {
+                    last_buyer[sequence] = buyer;
+                    bananas[sequence] += window[1] as u64;
+                }
+            }
+        }
+
+        Ok(bananas.into_iter().max().unwrap_or(0))
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::{BTreeSet, HashMap};
+
+use eyre::Result;
+use itertools::Itertools;
+
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day23;
+
+#[derive(Debug)]
+pub struct Network {
+    names: Vec<String>,
+    connections: Vec<BTreeSet<usize>>,
+}
+
+impl Network {
+    fn computers(&self) -> impl Iterator<Item = usize> {
+        0..self.names.len()
+    }
+}
+
+// Bron-Kerbosch with pivoting: extend the clique `r` with computers from `p`, where `x` holds the
+// computers that were already tried.
+fn largest_clique(
+    network: &Network,
+    r: Vec<usize>,
+    mut p: BTreeSet<usize>,
+    mut x: BTreeSet<usize>,
+) -> Vec<usize> {
+    let Some(&pivot) = p.union(&x).max_by_key(|&&c| network.connections[c].len()) else {
+        return r;
+    };
+
+    let mut largest = Vec::new();
+    let candidates: Vec<usize> = p.difference(&network.connections[pivot]).copied().collect();
+
+    for computer in candidates {
+        let connections = &network.connections[computer];
+        let mut clique = r.clone();
+        clique.push(computer);
+
+        let found = largest_clique(
+            network,
+            clique,
+            p.intersection(connections).copied().collect(),
+            x.intersection(connections).copied().collect(),
+        );
+        if found.len() > largest.len() {
+            largest = found;
+        }
+
+        p.remove(&computer);
+        x.insert(computer);
+    }
+
+    largest
+}
+
+impl Solution for Day23 {
+    type Input = Network;
+    type PartOne = usize;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut ids = HashMap::new();
+        let mut network = Network {
+            names: Vec::new(),
+            connections: Vec::new(),
+        };
+
+        for (i, line) in input.lines().enumerate() {
+            let (a, b) = line.split_once('-').ok_or_else(|| {
+                ParseError::new(i, line, line, "expected a connection like kh-tc")
+            })?;
+
+            let [a, b] = [a, b].map(|name| {
+                *ids.entry(name).or_insert_with(|| {
+                    network.names.push(name.to_string());
+                    network.connections.push(BTreeSet::new());
+                    network.names.len() - 1
+                })
+            });
+
+            network.connections[a].insert(b);
+            network.connections[b].insert(a);
+        }
+
+        Ok(network)
+    }
+
+    fn part_one(network: &Self::Input) -> Result<usize> {
+        let chief = |c: usize| network.names[c].starts_with('t');
+
+        // Count every triangle once, from its lowest computer.
+        Ok(network
+            .computers()
+            .flat_map(|a| {
+                let connections = &network.connections[a];
+                connections
+                    .range(a + 1..)
+                    .tuple_combinations()
+                    .filter(move |&(&b, &c)| network.connections[b].contains(&c))
+                    .map(move |(&b, &c)| [a, b, c])
+            })
+            .filter(|triangle| triangle.iter().any(|&c| chief(c)))
+            .count())
+    }
+
+    fn part_two(network: &Self::Input) -> Result<String> {
+        let clique = largest_clique(
+            network,
+            Vec::new(),
+            network.computers().collect(),
+            BTreeSet::new(),
+        );
+        Ok(clique
+            .iter()
+            .map(|&c| network.names[c].as_str())
+            .sorted()
+            .join(","))
+    }
+}
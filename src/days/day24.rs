@@ -0,0 +1,189 @@
+use std::collections::{BTreeSet, HashMap};
+
+use eyre::Result;
+use itertools::Itertools;
+
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day24;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    And,
+    Or,
+    Xor,
+}
+
+#[derive(Debug, Clone)]
+struct Gate {
+    inputs: [String; 2],
+    operation: Operation,
+    output: String,
+}
+
+#[derive(Debug)]
+pub struct Device {
+    initial: HashMap<String, bool>,
+    gates: Vec<Gate>,
+}
+
+// Evaluate the wire and everything it depends on. Wires that are being evaluated are `None` in
+// `values`, so that a loop fails instead of overflowing the stack.
+fn evaluate<'a>(
+    wire: &'a str,
+    device: &'a Device,
+    by_output: &HashMap<&str, &'a Gate>,
+    values: &mut HashMap<&'a str, Option<bool>>,
+) -> Result<bool> {
+    match values.get(wire) {
+        Some(Some(value)) => return Ok(*value),
+        Some(None) => eyre::bail!("wire {wire} is in a loop"),
+        None => (),
+    }
+    if let Some(&value) = device.initial.get(wire) {
+        return Ok(value);
+    }
+
+    let gate = by_output
+        .get(wire)
+        .ok_or_else(|| eyre::eyre!("nothing drives wire {wire}"))?;
+    values.insert(wire, None);
+
+    let [a, b] = &gate.inputs;
+    let a = evaluate(a, device, by_output, values)?;
+    let b = evaluate(b, device, by_output, values)?;
+
+    let value = match gate.operation {
+        Operation::And => a && b,
+        Operation::Or => a || b,
+        Operation::Xor => a != b,
+    };
+    values.insert(wire, Some(value));
+    Ok(value)
+}
+
+fn is_input(wire: &str) -> bool {
+    wire.starts_with('x') || wire.starts_with('y')
+}
+
+impl Solution for Day24 {
+    type Input = Device;
+    type PartOne = u64;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut device = Device {
+            initial: HashMap::new(),
+            gates: Vec::new(),
+        };
+
+        for (i, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some((wire, value)) = line.split_once(": ") {
+                let value = match value {
+                    "0" => false,
+                    "1" => true,
+                    _ => return Err(ParseError::new(i, line, value, "expected 0 or 1").into()),
+                };
+                device.initial.insert(wire.to_string(), value);
+                continue;
+            }
+
+            let Some((a, operation, b, "->", output)) =
+                line.split_ascii_whitespace().collect_tuple()
+            else {
+                return Err(ParseError::new(
+                    i,
+                    line,
+                    line,
+                    "expected a gate like x00 AND y00 -> z00",
+                )
+                .into());
+            };
+            let operation = match operation {
+                "AND" => Operation::And,
+                "OR" => Operation::Or,
+                "XOR" => Operation::Xor,
+                _ => return Err(ParseError::new(i, line, operation, "unknown operation").into()),
+            };
+            device.gates.push(Gate {
+                inputs: [a.to_string(), b.to_string()],
+                operation,
+                output: output.to_string(),
+            });
+        }
+
+        Ok(device)
+    }
+
+    fn part_one(device: &Self::Input) -> Result<u64> {
+        let by_output: HashMap<&str, &Gate> = device
+            .gates
+            .iter()
+            .map(|gate| (gate.output.as_str(), gate))
+            .collect();
+        let mut values = HashMap::new();
+
+        let mut z_wires: Vec<&str> = by_output
+            .keys()
+            .copied()
+            .filter(|wire| wire.starts_with('z'))
+            .collect();
+        z_wires.sort();
+
+        z_wires.iter().rev().try_fold(0, |number, wire| {
+            let bit = evaluate(wire, device, &by_output, &mut values)?;
+            Ok(number << 1 | u64::from(bit))
+        })
+    }
+
+    // The device is meant to be a ripple-carry adder, where every bit is
+    //
+    //     x XOR y -> s, s XOR carry -> z, x AND y -> c1, s AND carry -> c2, c1 OR c2 -> carry.
+    //
+    // A swapped output breaks one of the shapes that every gate in such an adder has, apart from
+    // the half adder for bit 0 and the final carry, which is the highest z.
+    fn part_two(device: &Self::Input) -> Result<String> {
+        let highest_z = device
+            .gates
+            .iter()
+            .map(|gate| gate.output.as_str())
+            .filter(|wire| wire.starts_with('z'))
+            .max()
+            .ok_or_else(|| eyre::eyre!("there are no z wires"))?;
+
+        let feeds = |wire: &str, operation: Operation| {
+            device.gates.iter().any(|gate| {
+                gate.operation == operation && gate.inputs.iter().any(|input| input == wire)
+            })
+        };
+
+        let mut wrong = BTreeSet::new();
+
+        for gate in &device.gates {
+            let output = gate.output.as_str();
+            let from_inputs = gate.inputs.iter().all(|input| is_input(input));
+            let first_bit = from_inputs && gate.inputs.iter().all(|input| input.ends_with("00"));
+
+            let correct = match gate.operation {
+                _ if output.starts_with('z') && output != highest_z => {
+                    gate.operation == Operation::Xor && (!from_inputs || first_bit)
+                }
+                Operation::Xor if from_inputs => first_bit || feeds(output, Operation::Xor),
+                Operation::Xor => false,
+                Operation::And => first_bit || feeds(output, Operation::Or),
+                Operation::Or => true,
+            };
+
+            if !correct {
+                wrong.insert(output);
+            }
+        }
+
+        Ok(wrong.iter().join(","))
+    }
+}
@@ -0,0 +1,95 @@
+use eyre::Result;
+use itertools::Itertools;
+
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day25;
+
+const HEIGHT: usize = 7;
+const WIDTH: usize = 5;
+
+#[derive(Debug, Default)]
+pub struct Schematics {
+    locks: Vec<[usize; WIDTH]>,
+    keys: Vec<[usize; WIDTH]>,
+}
+
+impl Solution for Day25 {
+    type Input = Schematics;
+    type PartOne = usize;
+    type PartTwo = &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut schematics = Schematics::default();
+
+        let mut first_line = 0;
+        for (i, schematic) in input.split("\n\n").enumerate() {
+            let grid = Grid::parse_with(schematic, |_position, c| match c {
+                '#' => Ok(true),
+                '.' => Ok(false),
+                _ => eyre::bail!("unexpected character"),
+            })
+            .map_err(|e| match e.downcast::<ParseError>() {
+                // Lines are counted from the start of the schematic, not of the input.
+                Ok(mut e) => {
+                    e.line += first_line;
+                    eyre::Report::new(e)
+                }
+                Err(e) => e,
+            })
+            .map_err(|e| e.wrap_err(format!("in schematic {}", i + 1)))?;
+            // The schematic's lines and the blank line after it.
+            first_line += schematic.split('\n').count() + 1;
+
+            let dimension = grid.dimension();
+            eyre::ensure!(
+                dimension.rows == HEIGHT && dimension.columns == WIDTH,
+                "schematic {} is {}x{}, expected {WIDTH}x{HEIGHT}",
+                i + 1,
+                dimension.columns,
+                dimension.rows,
+            );
+
+            let is_lock = grid.rows().next().unwrap().iter().all(|&filled| filled);
+            let is_key = grid
+                .rows()
+                .next_back()
+                .unwrap()
+                .iter()
+                .all(|&filled| filled);
+            let schematics = match (is_lock, is_key) {
+                (true, false) => &mut schematics.locks,
+                (false, true) => &mut schematics.keys,
+                _ => eyre::bail!("schematic {} is neither a lock nor a key", i + 1),
+            };
+
+            // The heights don't count the full row at the top of a lock or the bottom of a key,
+            // which also means every column has at least one filled cell.
+            let mut heights = [0; WIDTH];
+            for (height, column) in heights.iter_mut().zip(grid.columns()) {
+                *height = column.filter(|&&filled| filled).count() - 1;
+            }
+            schematics.push(heights);
+        }
+
+        Ok(schematics)
+    }
+
+    fn part_one(schematics: &Self::Input) -> Result<usize> {
+        Ok(schematics
+            .locks
+            .iter()
+            .cartesian_product(&schematics.keys)
+            .filter(|(lock, key)| {
+                std::iter::zip(*lock, *key).all(|(lock, key)| lock + key <= HEIGHT - 2)
+            })
+            .count())
+    }
+
+    // There is no puzzle for the second part of the last day.
+    fn part_two(_schematics: &Self::Input) -> Result<&'static str> {
+        Ok("Merry Christmas!")
+    }
+}
@@ -0,0 +1,248 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A cell on a grid, with rows growing downwards and columns growing to the right.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Position {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl Position {
+    /// Move by `v`, or `None` if that would leave the non-negative quadrant.
+    pub fn offset(self, v: Vec2) -> Option<Position> {
+        Some(Position {
+            row: self.row.checked_add_signed(v.d_row)?,
+            column: self.column.checked_add_signed(v.d_column)?,
+        })
+    }
+
+    /// Move one step in `direction`, or `None` if that would leave `dimension`.
+    pub fn step(self, direction: Direction, dimension: Dimension) -> Option<Position> {
+        self.offset(direction.into())
+            .filter(|position| dimension.contains(*position))
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Dimension {
+    pub rows: usize,
+    pub columns: usize,
+}
+
+impl Dimension {
+    pub fn contains(&self, Position { row, column }: Position) -> bool {
+        row < self.rows && column < self.columns
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(self) -> impl Iterator<Item = Position> {
+        (0..self.rows)
+            .flat_map(move |row| (0..self.columns).map(move |column| Position { row, column }))
+    }
+}
+
+/// The signed difference between two positions.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Vec2 {
+    pub d_row: isize,
+    pub d_column: isize,
+}
+
+impl Neg for Vec2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            d_row: -self.d_row,
+            d_column: -self.d_column,
+        }
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Self;
+
+    fn add(self, rhs: Vec2) -> Self {
+        Self {
+            d_row: self.d_row + rhs.d_row,
+            d_column: self.d_column + rhs.d_column,
+        }
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Self;
+
+    fn sub(self, rhs: Vec2) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self {
+        Self {
+            d_row: self.d_row * rhs,
+            d_column: self.d_column * rhs,
+        }
+    }
+}
+
+impl Sub for Position {
+    type Output = Vec2;
+
+    fn sub(self, rhs: Position) -> Vec2 {
+        let d_row = isize::try_from(self.row).unwrap() - isize::try_from(rhs.row).unwrap();
+        let d_column = isize::try_from(self.column).unwrap() - isize::try_from(rhs.column).unwrap();
+        Vec2 { d_row, d_column }
+    }
+}
+
+impl Add<Vec2> for Position {
+    type Output = Option<Position>;
+
+    fn add(self, rhs: Vec2) -> Option<Position> {
+        self.offset(rhs)
+    }
+}
+
+impl Sub<Vec2> for Position {
+    type Output = Option<Position>;
+
+    fn sub(self, rhs: Vec2) -> Option<Position> {
+        self.offset(-rhs)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    const ALL8: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// The orthogonal directions, clockwise starting upwards.
+    pub fn all4() -> [Direction; 4] {
+        [
+            Direction::Up,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+        ]
+    }
+
+    /// The orthogonal and diagonal directions, clockwise starting upwards.
+    pub fn all8() -> [Direction; 8] {
+        Self::ALL8
+    }
+
+    fn rotate(self, eighths: usize) -> Self {
+        Self::ALL8[(self as usize + eighths) % 8]
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+}
+
+impl From<Direction> for Vec2 {
+    fn from(direction: Direction) -> Vec2 {
+        let (d_row, d_column) = match direction {
+            Direction::Up => (-1, 0),
+            Direction::UpRight => (-1, 1),
+            Direction::Right => (0, 1),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (1, 0),
+            Direction::DownLeft => (1, -1),
+            Direction::Left => (0, -1),
+            Direction::UpLeft => (-1, -1),
+        };
+        Vec2 { d_row, d_column }
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            '^' => Ok(Self::Up),
+            'v' => Ok(Self::Down),
+            '<' => Ok(Self::Left),
+            '>' => Ok(Self::Right),
+            _ => Err(c),
+        }
+    }
+}
+
+impl From<Direction> for char {
+    fn from(direction: Direction) -> char {
+        match direction {
+            Direction::Up => '^',
+            Direction::UpRight => '↗',
+            Direction::Right => '>',
+            Direction::DownRight => '↘',
+            Direction::Down => 'v',
+            Direction::DownLeft => '↙',
+            Direction::Left => '<',
+            Direction::UpLeft => '↖',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step() {
+        let dimension = Dimension {
+            rows: 2,
+            columns: 3,
+        };
+        let corner = Position { row: 0, column: 2 };
+
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::UpLeft.turn_left(), Direction::DownLeft);
+        assert_eq!(Direction::DownRight.opposite(), Direction::UpLeft);
+        assert_eq!(corner.step(Direction::Up, dimension), None);
+        assert_eq!(corner.step(Direction::Right, dimension), None);
+        assert_eq!(
+            corner.step(Direction::DownLeft, dimension),
+            Some(Position { row: 1, column: 1 })
+        );
+        assert_eq!(
+            corner - Position { row: 1, column: 0 },
+            Vec2 {
+                d_row: -1,
+                d_column: 2
+            }
+        );
+    }
+}
@@ -0,0 +1,240 @@
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use eyre::Result;
+
+use crate::geometry::{Dimension, Direction, Position};
+use crate::parse::ParseError;
+
+/// A dense, rectangular grid stored in row-major order.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    dimension: Dimension,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn<F>(dimension: Dimension, f: F) -> Self
+    where
+        F: FnMut(Position) -> T,
+    {
+        Self {
+            dimension,
+            cells: dimension.positions().map(f).collect(),
+        }
+    }
+
+    /// Parse one cell per character, with one row per line. All rows must have the same length.
+    ///
+    /// Errors, including those returned by `f`, are reported as a [`ParseError`] at the offending
+    /// character.
+    pub fn parse_with<F>(s: &str, mut f: F) -> Result<Self>
+    where
+        F: FnMut(Position, char) -> Result<T>,
+    {
+        let mut dimension = Dimension::default();
+        let mut cells = Vec::new();
+
+        for (row, line) in s.lines().enumerate() {
+            let columns = line.chars().count();
+
+            if row == 0 {
+                dimension.columns = columns;
+            } else if columns != dimension.columns {
+                let message = format!("row has {columns} columns, expected {}", dimension.columns);
+                return Err(ParseError::at(row, line, 0, line, message).into());
+            }
+
+            for (column, c) in line.chars().enumerate() {
+                let cell = f(Position { row, column }, c).map_err(|e| {
+                    ParseError::at(row, line, column, &c.to_string(), format!("{e:#}"))
+                })?;
+                cells.push(cell);
+            }
+
+            dimension.rows += 1;
+        }
+
+        Ok(Self { dimension, cells })
+    }
+
+    pub fn dimension(&self) -> Dimension {
+        self.dimension
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        self.dimension
+            .contains(position)
+            .then(|| position.row * self.dimension.columns + position.column)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|i| &mut self.cells[i])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.dimension.positions().zip(self.cells.iter())
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            dimension: self.dimension,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        let columns = self.dimension.columns;
+        (0..self.dimension.rows).map(move |row| &self.cells[row * columns..(row + 1) * columns])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.dimension.columns).map(move |column| {
+            (0..self.dimension.rows).map(move |row| &self[Position { row, column }])
+        })
+    }
+
+    /// The diagonals running from top left to bottom right, starting in the top right corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let Dimension { rows, columns } = self.dimension;
+        let start_points = (0..columns)
+            .rev()
+            .map(|column| Position { row: 0, column })
+            .chain((1..rows).map(|row| Position { row, column: 0 }));
+
+        start_points.map(move |start| {
+            (0..)
+                .map(move |i| Position {
+                    row: start.row + i,
+                    column: start.column + i,
+                })
+                .map_while(|position| self.get(position))
+        })
+    }
+
+    /// The diagonals running from top right to bottom left, starting in the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let Dimension { rows, columns } = self.dimension;
+        let start_points = (0..columns)
+            .map(|column| Position { row: 0, column })
+            .chain((1..rows).map(move |row| Position {
+                row,
+                column: columns - 1,
+            }));
+
+        start_points.map(move |start| {
+            (0..=start.column)
+                .map(move |i| Position {
+                    row: start.row + i,
+                    column: start.column - i,
+                })
+                .map_while(|position| self.get(position))
+        })
+    }
+
+    /// The orthogonally adjacent positions inside the grid, clockwise starting upwards.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> {
+        let dimension = self.dimension;
+        Direction::all4()
+            .into_iter()
+            .filter_map(move |direction| position.step(direction, dimension))
+    }
+
+    /// The orthogonally and diagonally adjacent positions inside the grid, clockwise starting
+    /// upwards.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> {
+        let dimension = self.dimension;
+        Direction::all8()
+            .into_iter()
+            .filter_map(move |direction| position.step(direction, dimension))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of {:?}", self.dimension))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let dimension = self.dimension;
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of {dimension:?}"))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse_with(s, |_position, c| Ok(c))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid() {
+        let grid: Grid<char> = "abc\ndef\n".parse().unwrap();
+        let corner = Position { row: 0, column: 0 };
+
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+        assert_eq!(
+            grid.diagonals()
+                .map(|diagonal| diagonal.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["c", "bf", "ae", "d"]
+        );
+        assert_eq!(
+            grid.anti_diagonals()
+                .map(|diagonal| diagonal.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["a", "bd", "ce", "f"]
+        );
+        assert_eq!(
+            grid.neighbours4(corner)
+                .map(|p| grid[p])
+                .collect::<String>(),
+            "bd"
+        );
+        assert_eq!(
+            grid.neighbours8(corner)
+                .map(|p| grid[p])
+                .collect::<String>(),
+            "bed"
+        );
+        assert!("ab\nc".parse::<Grid<char>>().is_err());
+    }
+}
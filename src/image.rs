@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::time::Duration;
+
+use eyre::{Result, WrapErr};
+
+use crate::geometry::Dimension;
+use crate::grid::Grid;
+
+/// A colour with 8 bits per channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+    /// A grey between black at 0 and white at 1.
+    pub fn grey(brightness: f64) -> Rgb {
+        let v = (brightness.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Rgb(v, v, v)
+    }
+
+    /// One of a sequence of colours where neighbours differ a lot, for telling ids apart.
+    pub fn distinct(index: usize) -> Rgb {
+        // Stepping the hue by the golden ratio never repeats and spreads the colours evenly.
+        let hue = (index as f64 * 0.618_033_988_749_895).fract() * 6.0;
+        let x = 1.0 - (hue % 2.0 - 1.0).abs();
+        let (r, g, b) = match hue as u32 {
+            0 => (1.0, x, 0.0),
+            1 => (x, 1.0, 0.0),
+            2 => (0.0, 1.0, x),
+            3 => (0.0, x, 1.0),
+            4 => (x, 0.0, 1.0),
+            _ => (1.0, 0.0, x),
+        };
+        let channel = |c: f64| (55.0 + c * 200.0).round() as u8;
+        Rgb(channel(r), channel(g), channel(b))
+    }
+}
+
+// The picture of a grid with every cell a `scale` by `scale` square, as the colour of every pixel
+// in row-major order.
+fn pixels<T>(grid: &Grid<T>, scale: usize, colour: &impl Fn(&T) -> Rgb) -> Vec<Rgb> {
+    let Dimension { rows, columns } = grid.dimension();
+    let mut pixels = Vec::with_capacity(rows * columns * scale * scale);
+
+    for row in grid.rows() {
+        let colours: Vec<Rgb> = row.iter().map(colour).collect();
+        for _ in 0..scale {
+            for &c in &colours {
+                pixels.extend(std::iter::repeat_n(c, scale));
+            }
+        }
+    }
+
+    pixels
+}
+
+fn size(dimension: Dimension, scale: usize) -> (usize, usize) {
+    (dimension.columns * scale, dimension.rows * scale)
+}
+
+/// Write a picture of `grid` as a PNG, with every cell a `scale` by `scale` square in the colour
+/// `colour` gives it.
+pub fn write_png<T>(
+    writer: impl Write,
+    grid: &Grid<T>,
+    scale: usize,
+    colour: impl Fn(&T) -> Rgb,
+) -> Result<()> {
+    let (width, height) = size(grid.dimension(), scale);
+    let mut encoder = png::Encoder::new(
+        writer,
+        u32::try_from(width).wrap_err("the picture is too wide")?,
+        u32::try_from(height).wrap_err("the picture is too high")?,
+    );
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let data: Vec<u8> = pixels(grid, scale, &colour)
+        .into_iter()
+        .flat_map(|Rgb(r, g, b)| [r, g, b])
+        .collect();
+
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&data)?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// Writes pictures of grids as the frames of an animated GIF that repeats forever.
+pub struct GifWriter<W: Write> {
+    encoder: gif::Encoder<W>,
+    dimension: Dimension,
+    scale: usize,
+    delay: u16,
+}
+
+impl<W: Write> GifWriter<W> {
+    /// Start a GIF of grids of `dimension`, with every cell a `scale` by `scale` square and
+    /// `delay` between frames, in hundredths of a second.
+    pub fn new(writer: W, dimension: Dimension, scale: usize, delay: Duration) -> Result<Self> {
+        let (width, height) = size(dimension, scale);
+        let width = u16::try_from(width).wrap_err("the picture is too wide for a GIF")?;
+        let height = u16::try_from(height).wrap_err("the picture is too high for a GIF")?;
+
+        let mut encoder = gif::Encoder::new(writer, width, height, &[])?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+
+        Ok(Self {
+            encoder,
+            dimension,
+            scale,
+            delay: u16::try_from(delay.as_millis() / 10).unwrap_or(u16::MAX),
+        })
+    }
+
+    /// Add a picture of `grid` as the next frame. Every frame has its own palette, so it can use
+    /// at most 256 colours.
+    pub fn write_frame<T>(&mut self, grid: &Grid<T>, colour: impl Fn(&T) -> Rgb) -> Result<()> {
+        eyre::ensure!(
+            grid.dimension() == self.dimension,
+            "the frame is {:?}, expected {:?}",
+            grid.dimension(),
+            self.dimension
+        );
+
+        let mut indices = HashMap::new();
+        let mut palette = Vec::new();
+        let mut indexed = Vec::new();
+        for pixel in pixels(grid, self.scale, &colour) {
+            let index = match indices.get(&pixel) {
+                Some(&index) => index,
+                None => {
+                    let index = u8::try_from(indices.len())
+                        .wrap_err("a GIF frame cannot have more than 256 colours")?;
+                    let Rgb(r, g, b) = pixel;
+                    palette.extend([r, g, b]);
+                    indices.insert(pixel, index);
+                    index
+                }
+            };
+            indexed.push(index);
+        }
+
+        let (width, height) = size(self.dimension, self.scale);
+        let mut frame =
+            gif::Frame::from_palette_pixels(width as u16, height as u16, indexed, palette, None);
+        frame.delay = self.delay;
+        self.encoder.write_frame(&frame)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pixels() {
+        let grid: Grid<char> = "ab\n".parse().unwrap();
+        let colour = |c: &char| if *c == 'a' { Rgb::BLACK } else { Rgb::WHITE };
+        assert_eq!(
+            pixels(&grid, 2, &colour),
+            [Rgb::BLACK, Rgb::BLACK, Rgb::WHITE, Rgb::WHITE].repeat(2)
+        );
+
+        let mut png = Vec::new();
+        write_png(&mut png, &grid, 2, colour).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+
+        let mut gif = Vec::new();
+        let mut writer = GifWriter::new(&mut gif, grid.dimension(), 2, Duration::ZERO).unwrap();
+        writer.write_frame(&grid, colour).unwrap();
+        drop(writer);
+        assert!(gif.starts_with(b"GIF89a"));
+    }
+}
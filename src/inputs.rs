@@ -0,0 +1,142 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use eyre::{Result, WrapErr};
+use tracing::warn;
+
+/// Puzzle inputs stored as `dayNN.txt` in one directory, `~/.cache/aoc/2024` by default.
+#[derive(Debug, Clone)]
+pub struct InputStore {
+    dir: PathBuf,
+}
+
+/// Something about an input that the parsers may trip over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputIssue {
+    Empty,
+    CrLf,
+    MissingTrailingNewline,
+}
+
+impl Display for InputIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            InputIssue::Empty => write!(f, "the input is empty"),
+            InputIssue::CrLf => write!(f, "the input has CRLF line endings"),
+            InputIssue::MissingTrailingNewline => write!(f, "the input has no trailing newline"),
+        }
+    }
+}
+
+pub fn check(input: &str) -> Vec<InputIssue> {
+    if input.is_empty() {
+        return vec![InputIssue::Empty];
+    }
+
+    let mut issues = Vec::new();
+    if input.contains("\r\n") {
+        issues.push(InputIssue::CrLf);
+    }
+    if !input.ends_with('\n') {
+        issues.push(InputIssue::MissingTrailingNewline);
+    }
+    issues
+}
+
+/// Fix the line endings and the trailing newline, the issues that [`check`] reports.
+pub fn normalise(input: &str) -> String {
+    let mut input = input.replace("\r\n", "\n");
+    if !input.is_empty() && !input.ends_with('\n') {
+        input.push('\n');
+    }
+    input
+}
+
+impl InputStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The store in the `AOC_INPUTS` directory if set, otherwise in the user's cache directory.
+    pub fn from_env() -> Result<Self> {
+        if let Some(dir) = std::env::var_os("AOC_INPUTS") {
+            return Ok(Self::new(dir));
+        }
+
+        let cache = match std::env::var_os("XDG_CACHE_HOME") {
+            Some(dir) => PathBuf::from(dir),
+            None => std::env::home_dir()
+                .ok_or_else(|| eyre::eyre!("cannot find the home directory, set AOC_INPUTS"))?
+                .join(".cache"),
+        };
+        Ok(Self::new(cache.join("aoc/2024")))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, day: u32) -> PathBuf {
+        self.dir.join(format!("day{day:02}.txt"))
+    }
+
+    pub fn contains(&self, day: u32) -> bool {
+        self.path(day).is_file()
+    }
+
+    /// Read the input of `day`, warning about anything [`check`] finds.
+    pub fn load(&self, day: u32) -> Result<String> {
+        let path = self.path(day);
+        let input = std::fs::read_to_string(&path).wrap_err_with(|| {
+            format!("no input for day {day}, import it with `aoc input import {day} <file>`")
+        })?;
+
+        for issue in check(&input) {
+            warn!("day {day}: {issue} ({path:?})");
+        }
+
+        Ok(input)
+    }
+
+    /// Copy `source` into the store as the input of `day`, normalised. Returns the issues that
+    /// were fixed.
+    pub fn import(&self, day: u32, source: &Path, overwrite: bool) -> Result<Vec<InputIssue>> {
+        let input =
+            std::fs::read_to_string(source).wrap_err_with(|| format!("cannot read {source:?}"))?;
+        let issues = check(&input);
+        eyre::ensure!(!issues.contains(&InputIssue::Empty), "{source:?} is empty");
+
+        let path = self.path(day);
+        let normalised = normalise(&input);
+        if !overwrite {
+            if let Ok(existing) = std::fs::read_to_string(&path) {
+                eyre::ensure!(
+                    existing == normalised,
+                    "a different input for day {day} is already stored in {path:?}"
+                );
+            }
+        }
+
+        std::fs::create_dir_all(&self.dir)
+            .wrap_err_with(|| format!("cannot create {:?}", self.dir))?;
+        std::fs::write(&path, normalised).wrap_err_with(|| format!("cannot write {path:?}"))?;
+
+        Ok(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check("1 2\n3 4\n"), []);
+        assert_eq!(check(""), [InputIssue::Empty]);
+        assert_eq!(
+            check("1 2\r\n3 4"),
+            [InputIssue::CrLf, InputIssue::MissingTrailingNewline]
+        );
+        assert_eq!(normalise("1 2\r\n3 4"), "1 2\n3 4\n");
+    }
+}
@@ -0,0 +1,204 @@
+use std::any::Any;
+use std::fmt::Display;
+use std::io::Read;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use color_eyre::Section;
+use eyre::Result;
+
+use crate::params::Params;
+use crate::parse::ParseError;
+
+pub mod answers;
+pub mod days;
+pub mod geometry;
+pub mod grid;
+pub mod image;
+pub mod inputs;
+pub mod params;
+pub mod parse;
+pub mod search;
+
+pub use days::DAYS;
+
+/// A puzzle whose input is parsed once and then shared by both parts.
+pub trait Solution {
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Parse with explicit [`Params`]. Only days with puzzle parameters need to override this.
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        params.check_names(&[])?;
+        Self::parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne>;
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "a" | "A" | "1" => Ok(Part::One),
+            "b" | "B" | "2" => Ok(Part::Two),
+            _ => eyre::bail!("unknown part {s:?}, expected one of a, b, 1 or 2"),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "a"),
+            Part::Two => write!(f, "b"),
+        }
+    }
+}
+
+/// The outcome of solving one part, failures included so that other parts can still run.
+#[derive(Debug)]
+pub struct Answer {
+    pub part: Part,
+    pub value: Result<String>,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub parse_elapsed: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// The parsed input of a registered [`Day`], with its type erased.
+pub struct Parsed(Box<dyn Any>);
+
+/// A registered day with its [`Solution`] erased so that all days fit into one table.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u32,
+    parse: fn(&str, &Params) -> Result<Parsed>,
+    solve: fn(&Parsed, Part) -> Result<String>,
+}
+
+impl Day {
+    pub const fn new<S>(number: u32) -> Self
+    where
+        S: Solution,
+        S::Input: 'static,
+    {
+        Self {
+            number,
+            parse: parse::<S>,
+            solve: solve::<S>,
+        }
+    }
+
+    /// Parse the input with the real puzzle's parameters.
+    pub fn parse(&self, input: &str) -> Result<Parsed> {
+        self.parse_with(input, &Params::default())
+    }
+
+    /// Parse the input. A [`ParseError`] is tagged with the day and shows the offending line.
+    pub fn parse_with(&self, input: &str, params: &Params) -> Result<Parsed> {
+        (self.parse)(input, params).map_err(|mut report| {
+            let Some(e) = report.downcast_mut::<ParseError>() else {
+                return report;
+            };
+            e.day = Some(self.number);
+            let snippet = e.snippet();
+            report.section(snippet)
+        })
+    }
+
+    /// Solve one part of input previously parsed by the same day.
+    pub fn solve_parsed(&self, parsed: &Parsed, part: Part) -> Result<String> {
+        (self.solve)(parsed, part)
+    }
+
+    /// Parse the input once and solve the given parts, timing each step.
+    pub fn run(&self, input: &str, params: &Params, parts: &[Part]) -> Result<Report> {
+        let start = Instant::now();
+        let parsed = self.parse_with(input, params)?;
+        let parse_elapsed = start.elapsed();
+
+        let answers = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let value = self.solve_parsed(&parsed, part);
+                Answer {
+                    part,
+                    value,
+                    elapsed: start.elapsed(),
+                }
+            })
+            .collect();
+
+        Ok(Report {
+            parse_elapsed,
+            answers,
+        })
+    }
+
+    pub fn solve(&self, input: &str, part: Part) -> Result<String> {
+        self.solve_parsed(&self.parse(input)?, part)
+    }
+
+    pub fn solve_with(&self, input: &str, params: &Params, part: Part) -> Result<String> {
+        self.solve_parsed(&self.parse_with(input, params)?, part)
+    }
+}
+
+impl std::fmt::Debug for Day {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Day").field("number", &self.number).finish()
+    }
+}
+
+fn parse<S>(input: &str, params: &Params) -> Result<Parsed>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    Ok(Parsed(Box::new(S::parse_with(input, params)?)))
+}
+
+fn solve<S>(Parsed(input): &Parsed, part: Part) -> Result<String>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    let input = input
+        .downcast_ref::<S::Input>()
+        .ok_or_else(|| eyre::eyre!("input was parsed by a different day"))?;
+    match part {
+        Part::One => S::part_one(input).map(|answer| answer.to_string()),
+        Part::Two => S::part_two(input).map(|answer| answer.to_string()),
+    }
+}
+
+pub fn day(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+pub fn read_input() -> Result<String> {
+    let mut s = String::new();
+    std::io::stdin().read_to_string(&mut s)?;
+    Ok(s)
+}
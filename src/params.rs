@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use eyre::{Result, WrapErr};
+use serde::Deserialize;
+
+/// Puzzle parameters that the puzzle text gives rather than the input, like the size of the space
+/// the robots of day 14 move in. Days use the real puzzle's values for parameters that aren't
+/// given. The examples use different values, so their fixtures give them in a `params.toml`, e.g.
+///
+/// ```toml
+/// width = 11
+/// height = 7
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Params(BTreeMap<String, i64>);
+
+impl Params {
+    /// Fail on parameters the day doesn't know, so that a typo doesn't fall back to the real
+    /// puzzle's value without a word.
+    pub fn check_names(&self, known: &[&str]) -> Result<()> {
+        let unknown: Vec<&str> = self
+            .0
+            .keys()
+            .map(String::as_str)
+            .filter(|name| !known.contains(name))
+            .collect();
+        match (unknown.is_empty(), known.is_empty()) {
+            (true, _) => Ok(()),
+            (false, true) => eyre::bail!("unknown parameters {unknown:?}, the day takes none"),
+            (false, false) => {
+                eyre::bail!("unknown parameters {unknown:?}, expected some of {known:?}")
+            }
+        }
+    }
+
+    /// The value of the parameter `name`, or `default` if it isn't given.
+    pub fn get<T>(&self, name: &str, default: T) -> Result<T>
+    where
+        T: TryFrom<i64>,
+        T::Error: std::error::Error + Send + Sync + 'static,
+    {
+        match self.0.get(name) {
+            Some(&value) => T::try_from(value).wrap_err_with(|| format!("bad {name} {value}")),
+            None => Ok(default),
+        }
+    }
+}
+
+impl FromStr for Params {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        toml::from_str(s).wrap_err("expected parameters like `width = 11`, one per line")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_params() {
+        let params: Params = "width = 11\nheight = 7".parse().unwrap();
+        assert_eq!(params.get("width", 101usize).unwrap(), 11);
+        assert_eq!(params.get("depth", 3usize).unwrap(), 3);
+        assert!(params.check_names(&["width", "height"]).is_ok());
+        assert!(params.check_names(&["width"]).is_err());
+
+        let negative: Params = "width = -1".parse().unwrap();
+        assert!(negative.get("width", 101usize).is_err());
+        assert!("width = eleven".parse::<Params>().is_err());
+    }
+}
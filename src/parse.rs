@@ -0,0 +1,100 @@
+use std::fmt::Display;
+
+/// An error in the puzzle input, pointing at the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Filled in by [`crate::Day::parse`], parsers don't need to know which day they belong to.
+    pub day: Option<u32>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column number, counted in characters.
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+    source_line: String,
+}
+
+impl ParseError {
+    /// Create an error for `text` in the line with 0-based index `line_index`.
+    ///
+    /// `text` should be a subslice of `line`, otherwise the error points at the start of the line.
+    pub fn new(line_index: usize, line: &str, text: &str, message: impl Display) -> Self {
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|offset| offset + text.len() <= line.len())
+            .unwrap_or(0);
+        Self::at(
+            line_index,
+            line,
+            line[..offset].chars().count(),
+            text,
+            message,
+        )
+    }
+
+    /// Create an error for `text` at the 0-based `column_index` in the line with 0-based index
+    /// `line_index`.
+    pub fn at(
+        line_index: usize,
+        line: &str,
+        column_index: usize,
+        text: &str,
+        message: impl Display,
+    ) -> Self {
+        Self {
+            day: None,
+            line: line_index + 1,
+            column: column_index + 1,
+            text: text.to_string(),
+            message: message.to_string(),
+            source_line: line.to_string(),
+        }
+    }
+
+    /// The offending line with the offending text underlined.
+    pub fn snippet(&self) -> String {
+        let gutter = self.line.to_string().len();
+        let underline = "^".repeat(self.text.chars().count().max(1));
+        format!(
+            "{line:>gutter$} | {source_line}\n{empty:>gutter$} | {empty:>indent$}{underline}",
+            line = self.line,
+            source_line = self.source_line,
+            empty = "",
+            indent = self.column - 1,
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}, ")?;
+        }
+        write!(
+            f,
+            "line {}, column {}: {}: {:?}",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let line = "3   x4";
+        let mut e = ParseError::new(9, line, &line[4..], "cannot parse");
+        e.day = Some(1);
+
+        assert_eq!(e.column, 5);
+        assert_eq!(
+            e.to_string(),
+            r#"day 1, line 10, column 5: cannot parse: "x4""#
+        );
+        assert_eq!(e.snippet(), "10 | 3   x4\n   |     ^^");
+    }
+}
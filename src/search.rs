@@ -0,0 +1,404 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::geometry::Position;
+use crate::grid::Grid;
+
+/// A node reached by a search, with the cost of reaching it and the node it was reached from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reached<N, C> {
+    pub node: N,
+    pub cost: C,
+    pub parent: Option<N>,
+}
+
+/// How a search remembers the nodes it has already visited.
+pub trait Visited<N> {
+    /// Mark `node` as visited, returning whether it wasn't visited before.
+    fn insert(&mut self, node: &N) -> bool;
+}
+
+impl<N: Eq + Hash + Clone> Visited<N> for HashSet<N> {
+    fn insert(&mut self, node: &N) -> bool {
+        HashSet::insert(self, node.clone())
+    }
+}
+
+/// A dense visited set for searches over the positions of a grid of the same dimension.
+impl Visited<Position> for Grid<bool> {
+    fn insert(&mut self, node: &Position) -> bool {
+        !std::mem::replace(&mut self[*node], true)
+    }
+}
+
+/// Never remembers anything, so a node is visited once for every path to it. Only for graphs
+/// without cycles, where it enumerates all paths.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EveryPath;
+
+impl<N> Visited<N> for EveryPath {
+    fn insert(&mut self, _node: &N) -> bool {
+        true
+    }
+}
+
+/// Breadth-first search, yielding nodes in order of the number of steps from the starts.
+pub struct Bfs<N, F, V> {
+    queue: VecDeque<Reached<N, usize>>,
+    neighbours: F,
+    visited: V,
+}
+
+pub fn bfs<N, F, I, V>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: F,
+    mut visited: V,
+) -> Bfs<N, F, V>
+where
+    N: Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    V: Visited<N>,
+{
+    let queue = starts
+        .into_iter()
+        .filter(|node| visited.insert(node))
+        .map(|node| Reached {
+            node,
+            cost: 0,
+            parent: None,
+        })
+        .collect();
+
+    Bfs {
+        queue,
+        neighbours,
+        visited,
+    }
+}
+
+impl<N, F, I, V> Iterator for Bfs<N, F, V>
+where
+    N: Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    V: Visited<N>,
+{
+    type Item = Reached<N, usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let reached = self.queue.pop_front()?;
+
+        for node in (self.neighbours)(&reached.node) {
+            if self.visited.insert(&node) {
+                self.queue.push_back(Reached {
+                    node,
+                    cost: reached.cost + 1,
+                    parent: Some(reached.node.clone()),
+                });
+            }
+        }
+
+        Some(reached)
+    }
+}
+
+/// Depth-first search, yielding nodes in pre-order with the depth at which they were reached.
+/// Neighbours are explored in the order they are returned.
+pub struct Dfs<N, F, V> {
+    stack: Vec<Reached<N, usize>>,
+    neighbours: F,
+    visited: V,
+}
+
+pub fn dfs<N, F, I, V>(start: N, neighbours: F, visited: V) -> Dfs<N, F, V>
+where
+    N: Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    V: Visited<N>,
+{
+    Dfs {
+        stack: vec![Reached {
+            node: start,
+            cost: 0,
+            parent: None,
+        }],
+        neighbours,
+        visited,
+    }
+}
+
+impl<N, F, I, V> Iterator for Dfs<N, F, V>
+where
+    N: Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    V: Visited<N>,
+{
+    type Item = Reached<N, usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let reached = self.stack.pop()?;
+            if !self.visited.insert(&reached.node) {
+                continue;
+            }
+
+            let first_child = self.stack.len();
+            for node in (self.neighbours)(&reached.node) {
+                self.stack.push(Reached {
+                    node,
+                    cost: reached.cost + 1,
+                    parent: Some(reached.node.clone()),
+                });
+            }
+            self.stack[first_child..].reverse();
+
+            return Some(reached);
+        }
+    }
+}
+
+// Orders the queue of Dijkstra and A* by priority only, lowest first.
+struct Queued<N, C> {
+    priority: C,
+    reached: Reached<N, C>,
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Dijkstra's algorithm, yielding every node once with the lowest cost of reaching it, in order
+/// of cost. `neighbours` returns the nodes next to a node with the cost of the step.
+pub struct Dijkstra<N, C, F, V> {
+    queue: BinaryHeap<Queued<N, C>>,
+    neighbours: F,
+    visited: V,
+}
+
+pub fn dijkstra<N, C, F, I, V>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: F,
+    visited: V,
+) -> Dijkstra<N, C, F, V>
+where
+    N: Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    V: Visited<N>,
+{
+    let queue = starts
+        .into_iter()
+        .map(|node| Queued {
+            priority: C::default(),
+            reached: Reached {
+                node,
+                cost: C::default(),
+                parent: None,
+            },
+        })
+        .collect();
+
+    Dijkstra {
+        queue,
+        neighbours,
+        visited,
+    }
+}
+
+impl<N, C, F, I, V> Iterator for Dijkstra<N, C, F, V>
+where
+    N: Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    V: Visited<N>,
+{
+    type Item = Reached<N, C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Queued { reached, .. } = self.queue.pop()?;
+            if !self.visited.insert(&reached.node) {
+                continue;
+            }
+
+            for (node, step) in (self.neighbours)(&reached.node) {
+                let cost = reached.cost + step;
+                self.queue.push(Queued {
+                    priority: cost,
+                    reached: Reached {
+                        node,
+                        cost,
+                        parent: Some(reached.node.clone()),
+                    },
+                });
+            }
+
+            return Some(reached);
+        }
+    }
+}
+
+/// Follow the parents of the nodes yielded by a search until it reaches a goal, and return the
+/// path to it from a start with its cost.
+pub fn find_path<N, C>(
+    search: impl IntoIterator<Item = Reached<N, C>>,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+{
+    let mut parents = HashMap::new();
+
+    for Reached { node, cost, parent } in search {
+        if is_goal(&node) {
+            let mut path = vec![node];
+            let mut parent = parent;
+            while let Some(node) = parent {
+                parent = parents.get(&node).cloned().flatten();
+                path.push(node);
+            }
+            path.reverse();
+            return Some((path, cost));
+        }
+        parents.entry(node).or_insert(parent);
+    }
+
+    None
+}
+
+/// A* search from `start` to the nearest goal, guided by a `heuristic` that never overestimates
+/// the remaining cost. Returns the path and its cost.
+pub fn astar<N, C, F, I, H, G>(
+    start: N,
+    mut neighbours: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Ord + Copy + Default + Add<Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut best = HashMap::from([(start.clone(), C::default())]);
+    let mut queue = BinaryHeap::from([Queued {
+        priority: heuristic(&start),
+        reached: Reached {
+            node: start,
+            cost: C::default(),
+            parent: None,
+        },
+    }]);
+    let mut settled = HashSet::new();
+
+    let search = std::iter::from_fn(|| loop {
+        let Queued { reached, .. } = queue.pop()?;
+        if !settled.insert(reached.node.clone()) {
+            continue;
+        }
+
+        for (node, step) in neighbours(&reached.node) {
+            let cost = reached.cost + step;
+            if best.get(&node).is_some_and(|&best| best <= cost) {
+                continue;
+            }
+            best.insert(node.clone(), cost);
+            queue.push(Queued {
+                priority: cost + heuristic(&node),
+                reached: Reached {
+                    node,
+                    cost,
+                    parent: Some(reached.node.clone()),
+                },
+            });
+        }
+
+        return Some(reached);
+    });
+
+    find_path(search, &mut is_goal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Dimension;
+
+    #[test]
+    fn test_searches() {
+        let maze: Grid<char> = "..#.\n.##.\n....".parse().unwrap();
+        let start = Position { row: 0, column: 0 };
+        let goal = Position { row: 0, column: 3 };
+        let open = |p: &Position| {
+            maze.neighbours4(*p)
+                .filter(|n| maze[*n] == '.')
+                .collect::<Vec<_>>()
+        };
+        let weighted = |p: &Position| open(p).into_iter().map(|n| (n, 1)).collect::<Vec<_>>();
+        let visited = Grid::from_fn(maze.dimension(), |_| false);
+
+        let (path, steps) = find_path(bfs([start], open, visited.clone()), |p| *p == goal).unwrap();
+        assert_eq!(steps, 7);
+        assert_eq!(path.len(), 8);
+        assert_eq!((path[0], path[7]), (start, goal));
+
+        assert_eq!(dfs(start, open, HashSet::new()).count(), 9);
+        assert_eq!(
+            find_path(dijkstra([start], weighted, visited), |p| *p == goal).map(|(_, c)| c),
+            Some(7)
+        );
+
+        let manhattan = |p: &Position| p.row.abs_diff(goal.row) + p.column.abs_diff(goal.column);
+        assert_eq!(
+            astar(start, weighted, manhattan, |p| *p == goal).map(|(_, c)| c),
+            Some(7)
+        );
+
+        // Two paths lead from the corner to the opposite corner of a 2x2 grid.
+        let square = Dimension {
+            rows: 2,
+            columns: 2,
+        };
+        let down_right = |p: &Position| {
+            [
+                crate::geometry::Direction::Down,
+                crate::geometry::Direction::Right,
+            ]
+            .into_iter()
+            .filter_map(|d| p.step(d, square))
+            .collect::<Vec<_>>()
+        };
+        let corner = Position { row: 1, column: 1 };
+        assert_eq!(
+            dfs(start, down_right, EveryPath)
+                .filter(|r| r.node == corner)
+                .count(),
+            2
+        );
+    }
+}
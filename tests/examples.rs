@@ -0,0 +1,65 @@
+//! Solve every registered day against the worked examples from the puzzle text.
+//!
+//! Each day has a directory `tests/fixtures/dayNN` containing the example in `input.txt`, or in
+//! `input_a.txt` and `input_b.txt` when the parts use different examples, and the expected answer
+//! of each part in `answer_a.txt` and `answer_b.txt`. Parts without an answer file are skipped.
+//! Examples that use different puzzle parameters from the real puzzle give them in `params.toml`.
+
+use std::path::{Path, PathBuf};
+
+use advent_of_code_2024::params::Params;
+use advent_of_code_2024::{Part, DAYS};
+
+fn fixture_dir(number: u32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(format!("day{number:02}"))
+}
+
+fn read_fixture(dir: &Path, name: &str) -> Option<String> {
+    std::fs::read_to_string(dir.join(name)).ok()
+}
+
+#[test]
+fn test_examples() {
+    let mut failures = Vec::new();
+
+    for day in DAYS {
+        let dir = fixture_dir(day.number);
+        assert!(
+            dir.is_dir(),
+            "missing fixtures for day {}: {dir:?}",
+            day.number
+        );
+        let params: Params = read_fixture(&dir, "params.toml")
+            .map(|params| params.parse())
+            .transpose()
+            .unwrap_or_else(|e| panic!("bad parameters for day {}: {e:#}", day.number))
+            .unwrap_or_default();
+
+        for part in Part::ALL {
+            let Some(expected) = read_fixture(&dir, &format!("answer_{part}.txt")) else {
+                continue;
+            };
+            let input = read_fixture(&dir, &format!("input_{part}.txt"))
+                .or_else(|| read_fixture(&dir, "input.txt"))
+                .unwrap_or_else(|| panic!("missing example input for day {}", day.number));
+
+            match day.solve_with(&input, &params, part) {
+                Ok(actual) if actual == expected.trim() => (),
+                Ok(actual) => failures.push(format!(
+                    "day {} part {part}: expected {}, got {actual}",
+                    day.number,
+                    expected.trim()
+                )),
+                Err(e) => failures.push(format!("day {} part {part}: {e:#}", day.number)),
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "failed examples:\n{}",
+        failures.join("\n")
+    );
+}
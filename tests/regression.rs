@@ -0,0 +1,60 @@
+//! Solve every registered day against personal puzzle inputs and compare with the accepted answers.
+//!
+//! Inputs are read from the input store (`~/.cache/aoc/2024`, or `AOC_INPUTS`) and accepted answers
+//! from the answer ledger (`answers.toml` next to the inputs, or `AOC_ANSWERS`). Neither is
+//! committed, so days without an input and parts without an accepted answer are skipped.
+//!
+//! Answers used to be recorded as `answers/dayNN.toml` in the repository. Without a ledger they are
+//! still read from there, until `aoc answer import answers` copies them into the ledger.
+
+use std::path::Path;
+
+use advent_of_code_2024::answers::{Ledger, Verdict};
+use advent_of_code_2024::inputs::InputStore;
+use advent_of_code_2024::{Part, DAYS};
+
+#[test]
+fn test_recorded_answers() {
+    let inputs = InputStore::from_env().expect("cannot find the input store");
+    let mut ledger_path = Ledger::default_path().expect("cannot find the answer ledger");
+    let old_answers = Path::new(env!("CARGO_MANIFEST_DIR")).join("answers");
+    if std::env::var_os("AOC_ANSWERS").is_none() && !ledger_path.exists() && old_answers.is_dir() {
+        eprintln!("no answer ledger, reading the answers in {old_answers:?}");
+        ledger_path = old_answers;
+    }
+    let ledger = Ledger::load(&ledger_path).unwrap_or_else(|e| panic!("{e:#}"));
+    let mut failures = Vec::new();
+
+    for day in DAYS {
+        let Ok(input) = std::fs::read_to_string(inputs.path(day.number)) else {
+            eprintln!("skipping day {}: no stored input", day.number);
+            continue;
+        };
+
+        for part in Part::ALL {
+            let accepted = ledger
+                .entry(day.number, part)
+                .is_some_and(|entry| entry.accepted.is_some());
+            if !accepted {
+                continue;
+            }
+
+            match day.solve(&input, part) {
+                Ok(actual) => match ledger.check(day.number, part, &actual) {
+                    Verdict::Correct => (),
+                    verdict => failures.push(format!(
+                        "day {} part {part}: got {actual}, {verdict}",
+                        day.number
+                    )),
+                },
+                Err(e) => failures.push(format!("day {} part {part}: {e:#}", day.number)),
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "changed answers:\n{}",
+        failures.join("\n")
+    );
+}